@@ -0,0 +1,42 @@
+// Compares `find`, which compares the pattern at every position, with `find_rk`, which also rolls
+// a hash over the windows, on a long haystack and patterns of increasing length:
+//
+//     cargo run --release --example bench_find
+use fhestring::{MyClientKey, MAX_BLOCKS, MAX_FIND_LENGTH};
+use std::time::Instant;
+
+fn main() {
+    let my_client_key = MyClientKey::default_secure(MAX_BLOCKS);
+    let my_server_key = my_client_key.get_server_key();
+    let public_parameters = my_client_key.get_public_parameters();
+
+    let heistack_plain = "the quick brown fox jumps over the lazy dog ".repeat(4) + "zama rocks";
+    let heistack =
+        my_client_key.encrypt(&heistack_plain, 1, &public_parameters, &my_server_key.key);
+
+    println!("haystack of {} characters", heistack.len());
+    for needle_plain in ["za", "zama", "zama roc"] {
+        let needle = my_client_key.encrypt_no_padding(needle_plain);
+        let expected = heistack_plain.find(needle_plain).unwrap_or(MAX_FIND_LENGTH);
+
+        let start = Instant::now();
+        let res = my_server_key.find(&heistack, &needle, &public_parameters);
+        let find_time = start.elapsed();
+        let find_dec = my_client_key.decrypt_char(&res);
+
+        let start = Instant::now();
+        let res = my_server_key.find_rk(&heistack, &needle, &public_parameters);
+        let find_rk_time = start.elapsed();
+        let find_rk_dec = my_client_key.decrypt_char(&res);
+
+        println!(
+            "pattern of {} characters, expected {}: find {} in {:?}, find_rk {} in {:?}",
+            needle_plain.len(),
+            expected,
+            find_dec,
+            find_time,
+            find_rk_dec,
+            find_rk_time
+        );
+    }
+}
@@ -0,0 +1,30 @@
+// Built with `wasm-pack build --target web --out-dir examples/wasm/pkg -- --features wasm`
+import init, { WasmClientKey, WasmFheString } from "./pkg/fhestring.js";
+
+await init();
+
+// Key generation also derives the server key, it takes a while
+const key = new WasmClientKey();
+
+function download(bytes, name) {
+  const link = document.createElement("a");
+  link.href = URL.createObjectURL(new Blob([bytes]));
+  link.download = name;
+  link.click();
+}
+
+document.getElementById("encrypt").onclick = () => {
+  const plaintext = document.getElementById("plaintext").value;
+  const encrypted = key.encrypt(plaintext, 1);
+
+  download(key.server_key(), "server_key.bin");
+  download(key.public_parameters(), "public_parameters.bin");
+  download(encrypted.to_envelope(key), "input.bin");
+};
+
+document.getElementById("output").onchange = async (event) => {
+  const bytes = new Uint8Array(await event.target.files[0].arrayBuffer());
+  const result = WasmFheString.from_envelope(bytes, key);
+
+  document.getElementById("result").textContent = key.decrypt(result);
+};
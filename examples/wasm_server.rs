@@ -0,0 +1,36 @@
+// Native side of the browser example in examples/wasm.
+//
+// Reads the server key, the public parameters and the encrypted string that the page exported
+// to a directory, converts the string to uppercase and writes the result back for the page to
+// decrypt:
+//
+//     cargo run --release --example wasm_server -- <directory>
+use fhestring::{FheString, MyServerKey, PublicParameters};
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let directory = std::env::args()
+        .nth(1)
+        .expect("Usage: wasm_server <directory>");
+    let directory = Path::new(&directory);
+
+    let read = |name: &str| {
+        fs::read(directory.join(name)).unwrap_or_else(|err| panic!("Can't read {}: {}", name, err))
+    };
+
+    let my_server_key: MyServerKey =
+        bincode::deserialize(&read("server_key.bin")).expect("Invalid server key");
+    let public_parameters: PublicParameters =
+        bincode::deserialize(&read("public_parameters.bin")).expect("Invalid public parameters");
+    let my_string = FheString::from_envelope(&read("input.bin"), &public_parameters)
+        .expect("Invalid input envelope");
+
+    let result = my_server_key.to_upper(&my_string, &public_parameters);
+
+    fs::write(
+        directory.join("output.bin"),
+        result.to_envelope(&public_parameters),
+    )
+    .expect("Can't write output.bin");
+}
@@ -0,0 +1,51 @@
+/*
+ * C bindings of fhestring, link against the cdylib built by `cargo build --release`.
+ *
+ * Every handle is released with the matching free function, which accepts NULL. The functions
+ * that return a pointer return NULL on failure, including invalid input such as a string that
+ * is not ascii. Patterns are strings encrypted with a padding of 0.
+ */
+#ifndef FHESTRING_H
+#define FHESTRING_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct FfiClientKey FfiClientKey;
+typedef struct FfiServerKey FfiServerKey;
+typedef struct FheString FheString;
+typedef struct FheAsciiChar FheAsciiChar;
+
+FfiClientKey *fhestring_client_key_new(void);
+void fhestring_client_key_free(FfiClientKey *key);
+
+FfiServerKey *fhestring_server_key_new(const FfiClientKey *key);
+void fhestring_server_key_free(FfiServerKey *key);
+
+FheString *fhestring_encrypt(const FfiClientKey *key, const char *string, size_t padding);
+void fhestring_string_free(FheString *string);
+
+/* The result is released with fhestring_c_string_free */
+char *fhestring_decrypt(const FfiClientKey *key, const FheString *string);
+void fhestring_c_string_free(char *string);
+
+/* Returns 0 on success and -1 on failure */
+int fhestring_decrypt_char(const FfiClientKey *key, const FheAsciiChar *c, uint8_t *out);
+void fhestring_char_free(FheAsciiChar *c);
+
+FheAsciiChar *fhestring_contains(const FfiServerKey *key, const FheString *string,
+                                 const FheString *pattern);
+FheAsciiChar *fhestring_find(const FfiServerKey *key, const FheString *string,
+                             const FheString *pattern);
+FheString *fhestring_replace(const FfiServerKey *key, const FheString *string,
+                             const FheString *from, const FheString *to);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
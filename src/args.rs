@@ -23,6 +23,10 @@ pub struct StringArgs {
     /// What will replace it (for replace algorithms)
     #[arg(short, long)]
     pub to: String,
+
+    /// Use the faster multi-bit parameter set instead of the default one
+    #[arg(long)]
+    pub fast: bool,
 }
 
 impl StringArgs {
@@ -0,0 +1,152 @@
+use super::fhestring::FheString;
+use super::public_parameters::PublicParameters;
+use std::fmt;
+
+// Layout of an envelope, all integers are little endian:
+// magic (4 bytes) | format version (2 bytes) | parameters fingerprint (8 bytes) | payload
+const MAGIC: [u8; 4] = *b"FHES";
+const HEADER_LEN: usize = MAGIC.len() + 2 + 8;
+
+// Bump this when the payload encoding changes, and keep decoding the older versions in
+// `FheString::from_envelope` so that persisted strings can still be loaded
+const FORMAT_VERSION: u16 = 1;
+
+#[derive(Debug)]
+pub enum EnvelopeError {
+    // Shorter than the header
+    Truncated,
+    // Not an envelope at all
+    BadMagic,
+    // Written by a newer version of the crate, or corrupted
+    UnsupportedVersion(u16),
+    // Encrypted under other keys or parameters than the current ones
+    ParametersMismatch { expected: u64, found: u64 },
+    // The header is valid but the payload can not be decoded
+    Payload(bincode::Error),
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvelopeError::Truncated => write!(f, "the envelope is shorter than its header"),
+            EnvelopeError::BadMagic => write!(f, "the bytes are not a FheString envelope"),
+            EnvelopeError::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported envelope format version {}, the latest is {}",
+                version, FORMAT_VERSION
+            ),
+            EnvelopeError::ParametersMismatch { expected, found } => write!(
+                f,
+                "the string was encrypted under other parameters (fingerprint {:016x}, expected \
+                 {:016x})",
+                found, expected
+            ),
+            EnvelopeError::Payload(err) => write!(f, "invalid envelope payload: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for EnvelopeError {}
+
+/// Identifies the public key and the number of blocks of a `PublicParameters`.
+///
+/// This is the 64 bit FNV-1a hash of their serialization, which is stable across builds unlike
+/// the std hasher. The public key also encodes the tfhe parameter set, so strings encrypted
+/// under another key or other parameters get a different fingerprint. Serializing the public
+/// key is not cheap, so when handling many envelopes compute this once and pass it to
+/// `FheString::to_envelope_with_fingerprint` and `FheString::from_envelope_with_fingerprint`.
+pub fn parameters_fingerprint(public_parameters: &PublicParameters) -> u64 {
+    let serialized =
+        bincode::serialize(public_parameters).expect("Public parameters are always serializable");
+
+    serialized.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl FheString {
+    /// Serializes the string in a versioned envelope, so that it can be persisted and loaded
+    /// back after upgrading the crate.
+    ///
+    /// The envelope starts with a magic number, the format version and the fingerprint of the
+    /// public parameters the string was encrypted under, see `from_envelope`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string = my_client_key.encrypt(
+    ///     "zama",
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let envelope = my_string.to_envelope(&public_parameters);
+    /// let restored = FheString::from_envelope(&envelope, &public_parameters).unwrap();
+    ///
+    /// assert_eq!(my_client_key.decrypt(restored), "zama");
+    /// ```
+    pub fn to_envelope(&self, public_parameters: &PublicParameters) -> Vec<u8> {
+        self.to_envelope_with_fingerprint(parameters_fingerprint(public_parameters))
+    }
+
+    /// Same as `to_envelope` with the fingerprint of the public parameters computed by the
+    /// caller with `parameters_fingerprint`, which saves serializing the public key every time.
+    pub fn to_envelope_with_fingerprint(&self, fingerprint: u64) -> Vec<u8> {
+        let payload = bincode::serialize(self).expect("FheString is always serializable");
+
+        let mut envelope = Vec::with_capacity(HEADER_LEN + payload.len());
+        envelope.extend_from_slice(&MAGIC);
+        envelope.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        envelope.extend_from_slice(&fingerprint.to_le_bytes());
+        envelope.extend_from_slice(&payload);
+        envelope
+    }
+
+    /// Deserializes a string written by `to_envelope`.
+    ///
+    /// The fingerprint in the envelope must match the one of `public_parameters`, otherwise the
+    /// ciphertexts would decrypt to garbage under the current keys and
+    /// `EnvelopeError::ParametersMismatch` is returned instead. Envelopes of an unknown format
+    /// version, such as one written by a newer version of the crate, are rejected.
+    pub fn from_envelope(
+        envelope: &[u8],
+        public_parameters: &PublicParameters,
+    ) -> Result<FheString, EnvelopeError> {
+        FheString::from_envelope_with_fingerprint(
+            envelope,
+            parameters_fingerprint(public_parameters),
+        )
+    }
+
+    /// Same as `from_envelope` with the fingerprint of the public parameters computed by the
+    /// caller with `parameters_fingerprint`.
+    pub fn from_envelope_with_fingerprint(
+        envelope: &[u8],
+        expected: u64,
+    ) -> Result<FheString, EnvelopeError> {
+        if envelope.len() < HEADER_LEN {
+            return Err(EnvelopeError::Truncated);
+        }
+
+        let (magic, rest) = envelope.split_at(MAGIC.len());
+        let (version, rest) = rest.split_at(2);
+        let (fingerprint, payload) = rest.split_at(8);
+
+        if magic != MAGIC {
+            return Err(EnvelopeError::BadMagic);
+        }
+
+        let version = u16::from_le_bytes(version.try_into().unwrap());
+        if version == 0 || version > FORMAT_VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(version));
+        }
+
+        let found = u64::from_le_bytes(fingerprint.try_into().unwrap());
+        if found != expected {
+            return Err(EnvelopeError::ParametersMismatch { expected, found });
+        }
+
+        // Only version 1 exists so far, the older versions get their own decoding here
+        bincode::deserialize(payload).map_err(EnvelopeError::Payload)
+    }
+}
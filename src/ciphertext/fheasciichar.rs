@@ -1,10 +1,12 @@
 use crate::ciphertext::public_parameters::PublicParameters;
 use crate::MAX_BLOCKS;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use tfhe::integer::ciphertext::BaseRadixCiphertext;
-use tfhe::integer::RadixClientKey;
+use tfhe::integer::{IntegerCiphertext, RadixClientKey};
 use tfhe::shortint::Ciphertext;
 
-#[derive(Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct FheAsciiChar {
     pub inner: BaseRadixCiphertext<Ciphertext>,
 }
@@ -32,6 +34,31 @@ impl FheAsciiChar {
         client_key.decrypt::<u8>(value)
     }
 
+    // Bootstraps every block, which resets the noise without changing the value. Propagating
+    // the carries skips the blocks that have none, so each block also goes through an identity
+    // lookup table, which always bootstraps it
+    pub fn refresh(&self, server_key: &tfhe::integer::ServerKey) -> FheAsciiChar {
+        let mut res = self.inner.clone();
+        server_key.full_propagate_parallelized(&mut res);
+
+        let shortint_key: &tfhe::shortint::ServerKey = server_key.as_ref();
+        let identity = shortint_key.generate_lookup_table(|x| x);
+        res.blocks_mut()
+            .par_iter_mut()
+            .for_each(|block| shortint_key.apply_lookup_table_assign(block, &identity));
+
+        FheAsciiChar::new(res)
+    }
+
+    // Sum of the sizes of the LWE ciphertexts of every block, in bytes
+    pub fn serialized_size(&self) -> usize {
+        self.inner
+            .blocks()
+            .iter()
+            .map(|block| block.ct.lwe_size().0 * std::mem::size_of::<u64>())
+            .sum()
+    }
+
     pub fn eq(&self, server_key: &tfhe::integer::ServerKey, other: &FheAsciiChar) -> FheAsciiChar {
         let res = server_key.eq_parallelized(&self.inner, &other.inner);
         FheAsciiChar::new(res.into_radix(MAX_BLOCKS, server_key))
@@ -90,6 +117,28 @@ impl FheAsciiChar {
         FheAsciiChar::new(res)
     }
 
+    // Wraps around like `u8::wrapping_mul`
+    pub fn scalar_mul(&self, server_key: &tfhe::integer::ServerKey, other: u8) -> FheAsciiChar {
+        let res = server_key.scalar_mul_parallelized(&self.inner, other);
+        FheAsciiChar::new(res)
+    }
+
+    // Oblivious multiplexer, returns the value of the first branch whose condition is set, or
+    // the default value if none is. Conditions must be either 0 or 1
+    pub fn select(
+        server_key: &tfhe::integer::ServerKey,
+        branches: &[(FheAsciiChar, FheAsciiChar)],
+        default: &FheAsciiChar,
+    ) -> FheAsciiChar {
+        // Go from the lowest priority branch to the highest so that the first set condition wins
+        branches
+            .iter()
+            .rev()
+            .fold(default.clone(), |result, (condition, value)| {
+                condition.if_then_else(server_key, value, &result)
+            })
+    }
+
     pub fn if_then_else(
         &self,
         server_key: &tfhe::integer::ServerKey,
@@ -103,24 +152,45 @@ impl FheAsciiChar {
         FheAsciiChar::new(res)
     }
 
-    pub fn is_whitespace(
-        &self,
-        server_key: &tfhe::integer::ServerKey,
-        public_parameters: &PublicParameters,
-    ) -> FheAsciiChar {
-        let space = FheAsciiChar::encrypt_trivial(0x20u8, public_parameters, server_key); // Space
-        let tab = FheAsciiChar::encrypt_trivial(0x09u8, public_parameters, server_key); // Horizontal Tab
-        let newline = FheAsciiChar::encrypt_trivial(0x0Au8, public_parameters, server_key); // Newline
-        let vertical_tab = FheAsciiChar::encrypt_trivial(0x0Bu8, public_parameters, server_key); // Vertical Tab
-        let form_feed = FheAsciiChar::encrypt_trivial(0x0Cu8, public_parameters, server_key); // Form Feed
-        let carriage_return = FheAsciiChar::encrypt_trivial(0x0Du8, public_parameters, server_key); // Carriage Return
-
-        let res1 = self.eq(server_key, &space);
-        let res2 = self.eq(server_key, &tab);
-        let res3 = self.eq(server_key, &newline);
-        let res4 = self.eq(server_key, &vertical_tab);
-        let res5 = self.eq(server_key, &form_feed);
-        let res6 = self.eq(server_key, &carriage_return);
+    // The scalar comparisons are cheaper than comparing against a trivial encryption when the
+    // other side is known in clear
+    pub fn scalar_eq(&self, server_key: &tfhe::integer::ServerKey, other: u8) -> FheAsciiChar {
+        let res = server_key.scalar_eq_parallelized(&self.inner, other);
+        FheAsciiChar::new(res.into_radix(MAX_BLOCKS, server_key))
+    }
+
+    pub fn scalar_ne(&self, server_key: &tfhe::integer::ServerKey, other: u8) -> FheAsciiChar {
+        let res = server_key.scalar_ne_parallelized(&self.inner, other);
+        FheAsciiChar::new(res.into_radix(MAX_BLOCKS, server_key))
+    }
+
+    pub fn scalar_le(&self, server_key: &tfhe::integer::ServerKey, other: u8) -> FheAsciiChar {
+        let res = server_key.scalar_le_parallelized(&self.inner, other);
+        FheAsciiChar::new(res.into_radix(MAX_BLOCKS, server_key))
+    }
+
+    pub fn scalar_lt(&self, server_key: &tfhe::integer::ServerKey, other: u8) -> FheAsciiChar {
+        let res = server_key.scalar_lt_parallelized(&self.inner, other);
+        FheAsciiChar::new(res.into_radix(MAX_BLOCKS, server_key))
+    }
+
+    pub fn scalar_ge(&self, server_key: &tfhe::integer::ServerKey, other: u8) -> FheAsciiChar {
+        let res = server_key.scalar_ge_parallelized(&self.inner, other);
+        FheAsciiChar::new(res.into_radix(MAX_BLOCKS, server_key))
+    }
+
+    pub fn scalar_gt(&self, server_key: &tfhe::integer::ServerKey, other: u8) -> FheAsciiChar {
+        let res = server_key.scalar_gt_parallelized(&self.inner, other);
+        FheAsciiChar::new(res.into_radix(MAX_BLOCKS, server_key))
+    }
+
+    pub fn is_whitespace(&self, server_key: &tfhe::integer::ServerKey) -> FheAsciiChar {
+        let res1 = self.scalar_eq(server_key, 0x20u8); // Space
+        let res2 = self.scalar_eq(server_key, 0x09u8); // Horizontal Tab
+        let res3 = self.scalar_eq(server_key, 0x0Au8); // Newline
+        let res4 = self.scalar_eq(server_key, 0x0Bu8); // Vertical Tab
+        let res5 = self.scalar_eq(server_key, 0x0Cu8); // Form Feed
+        let res6 = self.scalar_eq(server_key, 0x0Du8); // Carriage Return
 
         res1.bitor(server_key, &res2)
             .bitor(server_key, &res3)
@@ -129,34 +199,40 @@ impl FheAsciiChar {
             .bitor(server_key, &res6)
     }
 
-    pub fn is_uppercase(
-        &self,
-        server_key: &tfhe::integer::ServerKey,
-        public_parameters: &PublicParameters,
-    ) -> FheAsciiChar {
-        let uppercase_a = FheAsciiChar::encrypt_trivial(0x41u8, public_parameters, server_key); // 'A'
-        let uppercase_z = FheAsciiChar::encrypt_trivial(0x5Au8, public_parameters, server_key); // 'Z'
+    // The crate assumes ascii, but custom arithmetic on the characters can leave that range
+    pub fn is_ascii(&self, server_key: &tfhe::integer::ServerKey) -> FheAsciiChar {
+        self.scalar_lt(server_key, 0x80u8)
+    }
 
-        let res1 = self.ge(server_key, &uppercase_a);
-        let res2 = self.le(server_key, &uppercase_z);
+    pub fn is_uppercase(&self, server_key: &tfhe::integer::ServerKey) -> FheAsciiChar {
+        let res1 = self.scalar_ge(server_key, 0x41u8); // 'A'
+        let res2 = self.scalar_le(server_key, 0x5Au8); // 'Z'
 
         res1.bitand(server_key, &res2)
     }
 
-    pub fn is_lowercase(
-        &self,
-        server_key: &tfhe::integer::ServerKey,
-        public_parameters: &PublicParameters,
-    ) -> FheAsciiChar {
-        let lowercase_a = FheAsciiChar::encrypt_trivial(0x61u8, public_parameters, server_key); // 'a'
-        let lowercase_z = FheAsciiChar::encrypt_trivial(0x7Au8, public_parameters, server_key); // 'z'
+    pub fn is_lowercase(&self, server_key: &tfhe::integer::ServerKey) -> FheAsciiChar {
+        let res1 = self.scalar_ge(server_key, 0x61u8); // 'a'
+        let res2 = self.scalar_le(server_key, 0x7Au8); // 'z'
 
-        let res1 = self.ge(server_key, &lowercase_a);
-        let res2 = self.le(server_key, &lowercase_z);
+        res1.bitand(server_key, &res2)
+    }
+
+    pub fn is_ascii_digit(&self, server_key: &tfhe::integer::ServerKey) -> FheAsciiChar {
+        let res1 = self.scalar_ge(server_key, 0x30u8); // '0'
+        let res2 = self.scalar_le(server_key, 0x39u8); // '9'
 
         res1.bitand(server_key, &res2)
     }
 
+    pub fn is_ascii_alphanumeric(&self, server_key: &tfhe::integer::ServerKey) -> FheAsciiChar {
+        let res1 = self.is_ascii_digit(server_key);
+        let res2 = self.is_uppercase(server_key);
+        let res3 = self.is_lowercase(server_key);
+
+        res1.bitor(server_key, &res2).bitor(server_key, &res3)
+    }
+
     // Input must be either 0 or 1
     pub fn flip(
         &self,
@@ -1,10 +1,14 @@
 use super::public_parameters::PublicParameters;
 use crate::client_key::MyClientKey;
+use crate::server_key::MyServerKey;
 use crate::{FheAsciiChar, FheString};
 
 pub struct FheSplit {
     pub buffers: Vec<FheString>,
     pub pattern_found: FheAsciiChar,
+    // Upper bound of the total length of the content of the parts, used by `rejoin` to drop
+    // padding that can't hold any content
+    content_len_bound: usize,
 }
 
 impl FheSplit {
@@ -19,23 +23,135 @@ impl FheSplit {
             fhe_string_buffers.push(FheString::from_vec(buffer, public_parameters, server_key));
         }
 
+        // The parts are disjoint pieces of the split string, and every buffer is as long as it
+        let content_len_bound = fhe_string_buffers
+            .iter()
+            .map(FheString::len)
+            .max()
+            .unwrap_or(0);
+
         FheSplit {
             buffers: fhe_string_buffers,
             pattern_found,
+            content_len_bound,
         }
     }
 
-    // Equivalent to running collect() on the iterator
-    pub fn decrypt(fhe_split: FheSplit, my_client_key: &MyClientKey) -> (Vec<String>, u8) {
-        let mut plain_split = Vec::new();
+    /// Returns the split parts as `FheString`s without decrypting them, so that they can be
+    /// passed to further encrypted operations.
+    ///
+    /// Each part keeps the length of the buffer it was split into, which is at least the length
+    /// of the input string. The actual characters are at the start and the rest is `\0` padding,
+    /// so the parts are valid padded strings for every `MyServerKey` method.
+    pub fn into_fhe_strings(self) -> Vec<FheString> {
+        self.buffers
+    }
+
+    /// Applies an operation to every part of the split, like `s.split(p).map(|t| t.trim())`.
+    ///
+    /// Since it is not known which parts are empty, the operation is applied to all of them,
+    /// including the empty ones. The `pattern_found` flag is kept as is.
+    pub fn map(&self, f: impl Fn(&FheString) -> FheString) -> FheSplit {
+        let buffers = self.buffers.iter().map(f).collect::<Vec<FheString>>();
+
+        // The operation may lengthen the parts, only their new lengths bound their content
+        let content_len_bound = buffers.iter().map(FheString::len).sum();
+
+        FheSplit {
+            buffers,
+            pattern_found: self.pattern_found.clone(),
+            content_len_bound,
+        }
+    }
+
+    /// Joins the parts of the split back into a single string with `separator` between them, the
+    /// inverse of `split`.
+    ///
+    /// It is not known how many parts the split produced, so the separator is only inserted
+    /// before a part that is followed by a non empty part. The empty parts at the end are dropped,
+    /// so `split(s, ",").rejoin(",")` returns `s` without its trailing separators. This costs one
+    /// `concatenate` per separator and per part. After each of them the result is truncated to
+    /// the longest the joined content can be, the content of the parts and a separator between
+    /// each of them, so the result does not grow with the square of the number of parts.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a,,b";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let separator = my_client_key.encrypt_no_padding(",");
+    ///
+    /// let fhe_split = my_server_key.split(&my_string, &separator, &public_parameters);
+    /// let rejoined = fhe_split.rejoin(&separator, &my_server_key, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt(rejoined), "a,,b");
+    /// ```
+    pub fn rejoin(
+        &self,
+        separator: &[FheAsciiChar],
+        server_key: &MyServerKey,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &server_key.key);
 
-        for some_fhe_string in fhe_split.buffers {
-            let dec_string = my_client_key.decrypt(some_fhe_string);
-            plain_split.push(dec_string);
+        // The kth flag is set if one of the parts from the kth on is not empty, the parts are in
+        // the canonical layout so a part is empty if its first character is \0
+        let mut followed_by_non_empty = vec![zero.clone(); self.buffers.len()];
+        let mut non_empty_found = zero.clone();
+        for (k, buffer) in self.buffers.iter().enumerate().rev() {
+            if let Some(first_char) = buffer.iter().next() {
+                non_empty_found =
+                    non_empty_found.bitor(&server_key.key, &first_char.ne(&server_key.key, &zero));
+            }
+            followed_by_non_empty[k] = non_empty_found.clone();
         }
 
+        let max_len =
+            self.content_len_bound + separator.len() * self.buffers.len().saturating_sub(1);
+
+        let mut buffers = self.buffers.iter().zip(followed_by_non_empty.iter());
+        let mut result = match buffers.next() {
+            Some((first_buffer, _)) => first_buffer.clone(),
+            None => FheString::from_vec(vec![], public_parameters, &server_key.key),
+        };
+
+        for (buffer, is_followed_by_non_empty) in buffers {
+            let masked_separator = separator
+                .iter()
+                .map(|c| is_followed_by_non_empty.if_then_else(&server_key.key, c, &zero))
+                .collect::<Vec<FheAsciiChar>>();
+            let masked_separator =
+                FheString::from_vec(masked_separator, public_parameters, &server_key.key);
+
+            result = server_key.concatenate(&result, &masked_separator, public_parameters);
+            result = server_key.concatenate(&result, buffer, public_parameters);
+
+            // The result is in the canonical layout, so only padding goes past max_len
+            if result.len() > max_len {
+                let mut bytes = result.get_bytes();
+                bytes.truncate(max_len);
+                result = FheString::new(bytes, result.get_cst());
+            }
+        }
+
+        result
+    }
+
+    // Equivalent to running collect() on the iterator
+    pub fn decrypt(fhe_split: FheSplit, my_client_key: &MyClientKey) -> (Vec<String>, u8) {
         let plain_pattern_found = my_client_key.decrypt_char(&fhe_split.pattern_found);
 
+        let plain_split = fhe_split
+            .into_fhe_strings()
+            .into_iter()
+            .map(|some_fhe_string| my_client_key.decrypt(some_fhe_string))
+            .collect::<Vec<String>>();
+
         (plain_split, plain_pattern_found)
     }
 }
@@ -1,8 +1,11 @@
 use super::public_parameters::PublicParameters;
+use crate::client_key::MyClientKey;
+use crate::utils;
 use crate::FheAsciiChar;
+use serde::{Deserialize, Serialize};
 use std::ops::{Index, IndexMut, RangeTo};
 
-#[derive(Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct FheString {
     bytes: Vec<FheAsciiChar>,
     cst: FheAsciiChar,
@@ -16,6 +19,11 @@ pub enum Comparison {
 }
 
 impl FheString {
+    /// Wraps `bytes` as they are, without moving the \0 characters.
+    ///
+    /// The methods of `MyServerKey` expect the canonical `[content..., padding...]` layout, so
+    /// only use this for buffers that are known to be in that layout, or for intermediate buffers
+    /// that are normalized later on. Use `from_vec_normalized` otherwise.
     pub fn from_vec(
         bytes: Vec<FheAsciiChar>,
         public_parameters: &PublicParameters,
@@ -25,6 +33,91 @@ impl FheString {
         FheString { bytes, cst }
     }
 
+    /// Wraps `bytes` and moves every \0 character to the end, so that the result is in the
+    /// canonical `[content..., padding...]` layout. This is O(n^2) in the number of bytes.
+    pub fn from_vec_normalized(
+        bytes: Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+        server_key: &tfhe::integer::ServerKey,
+    ) -> Self {
+        utils::bubble_zeroes_right(
+            FheString::from_vec(bytes, public_parameters, server_key),
+            server_key,
+            public_parameters,
+        )
+    }
+
+    /// Encrypts a byte slice followed by `padding` \0 characters.
+    ///
+    /// Same as `MyClientKey::encrypt` but for binary data that is not a `&str`. The bytes must be
+    /// ASCII and not \0, use `from_bytes_unchecked` to skip this check.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string = FheString::from_ascii_bytes(
+    ///     b"zama",
+    ///     STRING_PADDING,
+    ///     &my_client_key,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// assert_eq!(my_client_key.decrypt_chars(&my_string), b"zama");
+    /// ```
+    pub fn from_ascii_bytes(
+        bytes: &[u8],
+        padding: usize,
+        client_key: &MyClientKey,
+        public_parameters: &PublicParameters,
+        server_key: &tfhe::integer::ServerKey,
+    ) -> Self {
+        assert!(
+            bytes.iter().all(|byte| byte.is_ascii() && *byte != 0),
+            "The input string must only contain ascii letters and not include null characters"
+        );
+
+        Self::from_bytes_unchecked(bytes, padding, client_key, public_parameters, server_key)
+    }
+
+    /// Same as `from_ascii_bytes` but accepts any byte. The methods of `MyServerKey` are only
+    /// defined for ASCII strings, and a \0 byte ends the string early.
+    pub fn from_bytes_unchecked(
+        bytes: &[u8],
+        padding: usize,
+        client_key: &MyClientKey,
+        public_parameters: &PublicParameters,
+        server_key: &tfhe::integer::ServerKey,
+    ) -> Self {
+        let fhe_bytes = bytes
+            .iter()
+            .copied()
+            .chain(std::iter::repeat(0u8).take(padding))
+            .map(|b| client_key.encrypt_char(b))
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::from_vec(fhe_bytes, public_parameters, server_key)
+    }
+
+    /// Appends one \0 character, so that the string is terminated even without padding.
+    ///
+    /// Every `MyServerKey` method accepts strings encrypted with any padding, including none.
+    /// The algorithms that look one position past the last visible character to detect the end
+    /// of the string (`rfind`, `replace`, `replacen` and the splits) rely on the string ending with
+    /// at least one \0, so they call this once on their input instead of checking it, which is not
+    /// possible on encrypted data.
+    pub fn terminated(
+        mut self,
+        public_parameters: &PublicParameters,
+        server_key: &tfhe::integer::ServerKey,
+    ) -> Self {
+        self.push(FheAsciiChar::encrypt_trivial(
+            0u8,
+            public_parameters,
+            server_key,
+        ));
+        self
+    }
+
     pub fn new(bytes: Vec<FheAsciiChar>, cst: FheAsciiChar) -> FheString {
         FheString { bytes, cst }
     }
@@ -34,6 +127,26 @@ impl FheString {
         self.bytes.len()
     }
 
+    /// Returns the plaintext capacity of the string, the number of encrypted characters
+    /// including the padding.
+    ///
+    /// This is an upper bound of the visible length, which is only known encrypted through
+    /// `MyServerKey::len`. Since the algorithms are oblivious their cost and the size of their
+    /// output only depend on this number, so use it to size buffers or to estimate the cost of
+    /// an operation.
+    pub fn len_upper_bound(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns an estimate of the number of bytes the encrypted characters take once serialized.
+    ///
+    /// This is the sum of the sizes of the ciphertexts of every block of every character,
+    /// including the padding, so it grows with `len_upper_bound`. The few bytes of metadata that
+    /// an encoding stores next to each block are not counted.
+    pub fn serialized_size(&self) -> usize {
+        self.bytes.iter().map(FheAsciiChar::serialized_size).sum()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.bytes.is_empty()
     }
@@ -1,5 +1,7 @@
+pub mod envelope;
 pub mod fheasciichar;
 pub mod fhesplit;
 pub mod fhestring;
 pub mod fhestrip;
 pub mod public_parameters;
+pub mod stringops;
@@ -0,0 +1,49 @@
+use super::public_parameters::PublicParameters;
+use crate::server_key::MyServerKey;
+use crate::FheString;
+use std::ops::Add;
+
+// Relational operators are not implemented since `PartialEq` and `PartialOrd` have
+// to return a plaintext bool, use the `MyServerKey` methods which return an encrypted one
+pub struct StringOps<'a> {
+    pub string: FheString,
+    server_key: &'a MyServerKey,
+    public_parameters: &'a PublicParameters,
+}
+
+impl<'a> StringOps<'a> {
+    pub fn new(
+        string: FheString,
+        server_key: &'a MyServerKey,
+        public_parameters: &'a PublicParameters,
+    ) -> Self {
+        StringOps {
+            string,
+            server_key,
+            public_parameters,
+        }
+    }
+
+    pub fn into_inner(self) -> FheString {
+        self.string
+    }
+}
+
+impl<'a> Add<&StringOps<'a>> for &StringOps<'a> {
+    type Output = StringOps<'a>;
+
+    fn add(self, other: &StringOps<'a>) -> Self::Output {
+        let string =
+            self.server_key
+                .concatenate(&self.string, &other.string, self.public_parameters);
+        StringOps::new(string, self.server_key, self.public_parameters)
+    }
+}
+
+impl<'a> Add for StringOps<'a> {
+    type Output = StringOps<'a>;
+
+    fn add(self, other: StringOps<'a>) -> Self::Output {
+        &self + &other
+    }
+}
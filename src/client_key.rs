@@ -2,9 +2,13 @@ use crate::ciphertext::fheasciichar::FheAsciiChar;
 use crate::ciphertext::fhestring::FheString;
 use crate::ciphertext::public_parameters::PublicParameters;
 use crate::server_key::MyServerKey;
+use crate::STRING_PADDING;
 use serde::{Deserialize, Serialize};
 use tfhe::integer::{gen_keys_radix, PublicKey, RadixClientKey};
-use tfhe::shortint::ClassicPBSParameters;
+use tfhe::shortint::parameters::{
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+};
+use tfhe::shortint::PBSParameters;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MyClientKey {
@@ -27,13 +31,34 @@ impl MyClientKey {
     }
 
     // Requirement to create key from params or directtly
-    pub fn from_params(params: ClassicPBSParameters, num_blocks: usize) -> Self {
-        let (client_key, server_key) = gen_keys_radix(params, num_blocks);
+    //
+    // Any classic or multi-bit parameter set works, as long as its message modulus is 4 so that
+    // num_blocks = MAX_BLOCKS blocks hold a byte. The characters are only changed with radix
+    // operations that propagate the carries, so the carry modulus does not matter
+    pub fn from_params(params: impl Into<PBSParameters>, num_blocks: usize) -> Self {
+        let (client_key, server_key) = gen_keys_radix(params.into(), num_blocks);
         let public_key = PublicKey::new(&client_key);
         let public_parameters = PublicParameters::new(public_key, num_blocks);
         MyClientKey::new(client_key, server_key, public_parameters)
     }
 
+    /// Generates keys with the default tfhe parameter set, `PARAM_MESSAGE_2_CARRY_2_KS_PBS`.
+    ///
+    /// This is the reference set that all the tests run with.
+    pub fn default_secure(num_blocks: usize) -> Self {
+        MyClientKey::from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks)
+    }
+
+    /// Generates keys with the multi-bit parameter set
+    /// `PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS`.
+    ///
+    /// Multi-bit bootstrapping processes 3 bits of the mask at a time and parallelizes well, so
+    /// it is faster on machines with many cores. tfhe targets the same 128 bits of security for
+    /// both presets, the difference is the speed and the failure probability of a bootstrap.
+    pub fn default_fast(num_blocks: usize) -> Self {
+        MyClientKey::from_params(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS, num_blocks)
+    }
+
     pub fn get_server_key(&self) -> MyServerKey {
         MyServerKey::new(self.server_key.clone())
     }
@@ -49,33 +74,127 @@ impl MyClientKey {
         public_parameters: &PublicParameters,
         server_key: &tfhe::integer::ServerKey,
     ) -> FheString {
+        FheString::from_ascii_bytes(
+            string.as_bytes(),
+            padding,
+            self,
+            public_parameters,
+            server_key,
+        )
+    }
+
+    /// Encrypts a string with the default padding of `STRING_PADDING` \0 characters.
+    ///
+    /// Every `MyServerKey` method accepts this padding, the ones that need an extra \0 to handle
+    /// the end of the string (`rfind`, `replace`, `replacen`, the splits and the comparisons) add
+    /// it themselves. A larger padding is never needed for correctness, it only hides the actual
+    /// length of the string better at the cost of slower operations. Use `encrypt` to choose it.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string = my_client_key.encrypt_auto("zama", &public_parameters, &my_server_key.key);
+    ///
+    /// assert_eq!(my_string.len_upper_bound(), "zama".len() + STRING_PADDING);
+    /// ```
+    pub fn encrypt_auto(
+        &self,
+        string: &str,
+        public_parameters: &PublicParameters,
+        server_key: &tfhe::integer::ServerKey,
+    ) -> FheString {
+        self.encrypt(string, STRING_PADDING, public_parameters, server_key)
+    }
+
+    /// Encrypts a string as a sequence of chunks of `chunk_len` characters, each followed by
+    /// `padding` \0 characters.
+    ///
+    /// The chunks are encrypted lazily while iterating, so a large document never has to be
+    /// held encrypted in memory at once, and the server can process each chunk independently as
+    /// it arrives. The last chunk holds the remaining characters and an empty string yields no
+    /// chunk.
+    ///
+    /// Operations that only look at one character at a time like `to_upper` or `count_in_set`
+    /// can be applied chunk by chunk. A match that crosses a chunk boundary is not seen by
+    /// `contains`, `find`, `replace` or the splits on either chunk, so for those the caller has
+    /// to encrypt overlapping chunks itself, overlapping by at least the pattern length minus
+    /// one, and deduplicate the results.
+    ///
+    /// # Example:
+    /// ```
+    /// let chunks = my_client_key
+    ///     .encrypt_chunks(
+    ///         "hello world",
+    ///         4,
+    ///         STRING_PADDING,
+    ///         &public_parameters,
+    ///         &my_server_key.key,
+    ///     )
+    ///     .map(|chunk| my_client_key.decrypt(chunk))
+    ///     .collect::<Vec<String>>();
+    ///
+    /// assert_eq!(chunks, vec!["hell", "o wo", "rld"]);
+    /// ```
+    pub fn encrypt_chunks<'a>(
+        &'a self,
+        string: &'a str,
+        chunk_len: usize,
+        padding: usize,
+        public_parameters: &'a PublicParameters,
+        server_key: &'a tfhe::integer::ServerKey,
+    ) -> impl Iterator<Item = FheString> + 'a {
+        assert!(chunk_len >= 1, "The chunk length must be at least 1");
+
+        string.as_bytes().chunks(chunk_len).map(move |chunk| {
+            FheString::from_ascii_bytes(chunk, padding, self, public_parameters, server_key)
+        })
+    }
+
+    pub fn encrypt_no_padding(&self, string: &str) -> Vec<FheAsciiChar> {
         assert!(
             string.chars().all(|char| char.is_ascii() && char != '\0'),
             "The input string must only contain ascii letters and not include null characters"
         );
 
-        let string = format!("{}{}", string, "\0".repeat(padding));
-
         let fhe_bytes = string
             .bytes()
             .map(|b| FheAsciiChar::encrypt(b, &self.client_key))
             .collect::<Vec<FheAsciiChar>>();
 
-        FheString::from_vec(fhe_bytes, public_parameters, server_key)
+        fhe_bytes
     }
 
-    pub fn encrypt_no_padding(&self, string: &str) -> Vec<FheAsciiChar> {
+    // Trivial encryptions are not secure but go through the exact same server side logic, so
+    // they are used to quickly test the algorithms
+    #[cfg(test)]
+    pub fn encrypt_trivial_string(&self, string: &str, padding: usize) -> FheString {
+        let mut fhe_string = FheString::from_vec(
+            self.encrypt_trivial_no_padding(string),
+            &self.public_paramters,
+            &self.server_key,
+        );
+
+        for _ in 0..padding {
+            fhe_string.push(FheAsciiChar::encrypt_trivial(
+                0u8,
+                &self.public_paramters,
+                &self.server_key,
+            ));
+        }
+
+        fhe_string
+    }
+
+    #[cfg(test)]
+    pub fn encrypt_trivial_no_padding(&self, string: &str) -> Vec<FheAsciiChar> {
         assert!(
             string.chars().all(|char| char.is_ascii() && char != '\0'),
             "The input string must only contain ascii letters and not include null characters"
         );
 
-        let fhe_bytes = string
+        string
             .bytes()
-            .map(|b| FheAsciiChar::encrypt(b, &self.client_key))
-            .collect::<Vec<FheAsciiChar>>();
-
-        fhe_bytes
+            .map(|b| FheAsciiChar::encrypt_trivial(b, &self.public_paramters, &self.server_key))
+            .collect::<Vec<FheAsciiChar>>()
     }
 
     pub fn decrypt_char(&self, cipher_char: &FheAsciiChar) -> u8 {
@@ -93,15 +212,79 @@ impl MyClientKey {
         }
     }
 
-    pub fn decrypt(&self, cipher_string: FheString) -> String {
+    /// Checks whether a `FheString` still decrypts to a valid string.
+    ///
+    /// The tfhe integer API does not expose how much noise a ciphertext carries, so this is a
+    /// heuristic that requires the secret key. Every character is decrypted and the result must
+    /// be in the layout the server key produces, ASCII characters followed by \0 padding. A
+    /// character corrupted by too much noise decrypts to an arbitrary value, which breaks this
+    /// layout with high probability. Use it after long chains of operations, `decrypt` panics on
+    /// invalid strings.
+    ///
+    /// # Arguments
+    /// * `cipher_string`: &FheString - The string to check.
+    ///
+    /// # Returns
+    /// `bool` - true if the string decrypts to ASCII characters followed by padding.
+    pub fn can_decrypt(&self, cipher_string: &FheString) -> bool {
+        let ascii_bytes = cipher_string
+            .iter()
+            .map(|fhe_b| self.decrypt_char(fhe_b))
+            .collect::<Vec<u8>>();
+
+        let content_length = ascii_bytes
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(ascii_bytes.len());
+
+        ascii_bytes[..content_length].is_ascii()
+            && ascii_bytes[content_length..].iter().all(|&byte| byte == 0)
+    }
+
+    /// Decrypts a `FheString` to the bytes before the first \0, without checking them.
+    pub fn decrypt_chars(&self, cipher_string: &FheString) -> Vec<u8> {
         let ascii_bytes = cipher_string
             .iter()
             .map(|fhe_b| self.client_key.decrypt::<u8>(&fhe_b.inner))
             .collect::<Vec<u8>>();
 
         // Truncate zeroes
-        let ascii_bytes = Self::truncate_at_null_byte(ascii_bytes);
+        Self::truncate_at_null_byte(ascii_bytes)
+    }
+
+    /// Decrypts a `FheString`.
+    ///
+    /// # Panics
+    /// If a decrypted character is not ASCII. This never happens for the strings returned by
+    /// `MyServerKey`, but arbitrary arithmetic on the characters can produce such bytes, use
+    /// `decrypt_checked` or `decrypt_lossy` for these strings.
+    pub fn decrypt(&self, cipher_string: FheString) -> String {
+        self.decrypt_checked(&cipher_string)
+            .expect("The decrypted string is not ASCII, use decrypt_lossy or decrypt_checked")
+    }
+
+    /// Decrypts a `FheString`, or returns the decrypted bytes if one of them is not ASCII.
+    pub fn decrypt_checked(&self, cipher_string: &FheString) -> Result<String, Vec<u8>> {
+        let ascii_bytes = self.decrypt_chars(cipher_string);
+
+        if ascii_bytes.is_ascii() {
+            Ok(ascii_bytes.into_iter().map(char::from).collect())
+        } else {
+            Err(ascii_bytes)
+        }
+    }
 
-        String::from_utf8(ascii_bytes).unwrap()
+    /// Decrypts a `FheString`, replacing every byte that is not ASCII with `?`.
+    pub fn decrypt_lossy(&self, cipher_string: &FheString) -> String {
+        self.decrypt_chars(cipher_string)
+            .into_iter()
+            .map(|byte| {
+                if byte.is_ascii() {
+                    char::from(byte)
+                } else {
+                    '?'
+                }
+            })
+            .collect()
     }
 }
@@ -0,0 +1,268 @@
+// C bindings of the main operations, built into the cdylib, see include/fhestring.h.
+//
+// The keys and the strings are passed as opaque pointers that the caller releases with the
+// matching free function. The library panics on invalid input, and a panic must not unwind into
+// C, so every entry point catches it and reports it like any other failure: with a NULL pointer,
+// or a negative status for the functions that return one.
+
+use crate::{FheAsciiChar, FheString, MyClientKey, MyServerKey, PublicParameters, MAX_BLOCKS};
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+// The client key with the server key and the public parameters every encryption needs
+pub struct FfiClientKey {
+    client_key: MyClientKey,
+    server_key: MyServerKey,
+    public_parameters: PublicParameters,
+}
+
+// The server key with the public parameters every operation needs
+pub struct FfiServerKey {
+    server_key: MyServerKey,
+    public_parameters: PublicParameters,
+}
+
+// Runs `f`, a panic is turned into None
+fn catch<T>(f: impl FnOnce() -> Option<T>) -> Option<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).ok().flatten()
+}
+
+fn into_handle<T>(value: Option<T>) -> *mut T {
+    value.map_or(ptr::null_mut(), |value| Box::into_raw(Box::new(value)))
+}
+
+unsafe fn free_handle<T>(handle: *mut T) {
+    if !handle.is_null() {
+        let value = Box::from_raw(handle);
+        let _ = panic::catch_unwind(AssertUnwindSafe(move || drop(value)));
+    }
+}
+
+/// Generates keys with the default parameter set, or returns NULL on failure.
+#[no_mangle]
+pub extern "C" fn fhestring_client_key_new() -> *mut FfiClientKey {
+    into_handle(catch(|| {
+        let client_key = MyClientKey::default_secure(MAX_BLOCKS);
+
+        Some(FfiClientKey {
+            server_key: client_key.get_server_key(),
+            public_parameters: client_key.get_public_parameters(),
+            client_key,
+        })
+    }))
+}
+
+/// Releases keys returned by `fhestring_client_key_new`.
+///
+/// # Safety
+/// `key` must be NULL or a pointer returned by `fhestring_client_key_new` that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_client_key_free(key: *mut FfiClientKey) {
+    free_handle(key)
+}
+
+/// Returns the server side of the keys, to run the operations with.
+///
+/// # Safety
+/// `key` must be NULL or a valid pointer returned by `fhestring_client_key_new`.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_server_key_new(key: *const FfiClientKey) -> *mut FfiServerKey {
+    into_handle(catch(|| {
+        let key = key.as_ref()?;
+
+        Some(FfiServerKey {
+            server_key: key.server_key.clone(),
+            public_parameters: key.public_parameters.clone(),
+        })
+    }))
+}
+
+/// Releases a server key returned by `fhestring_server_key_new`.
+///
+/// # Safety
+/// `key` must be NULL or a pointer returned by `fhestring_server_key_new` that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_server_key_free(key: *mut FfiServerKey) {
+    free_handle(key)
+}
+
+/// Encrypts a nul terminated ascii string followed by `padding` \0 characters. Patterns are
+/// encrypted with a padding of 0. Returns NULL if the string is not ascii.
+///
+/// # Safety
+/// `key` must be NULL or a valid pointer returned by `fhestring_client_key_new`, and `string`
+/// NULL or a valid nul terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_encrypt(
+    key: *const FfiClientKey,
+    string: *const c_char,
+    padding: usize,
+) -> *mut FheString {
+    into_handle(catch(|| {
+        let key = key.as_ref()?;
+        if string.is_null() {
+            return None;
+        }
+        let string = CStr::from_ptr(string).to_str().ok()?;
+        // Checked here rather than through the panic of `MyClientKey::encrypt`
+        if !string.is_ascii() {
+            return None;
+        }
+
+        Some(
+            key.client_key
+                .encrypt(string, padding, &key.public_parameters, &key.server_key.key),
+        )
+    }))
+}
+
+/// Decrypts a string into a nul terminated string, to release with `fhestring_c_string_free`.
+/// Returns NULL if a character is not ascii.
+///
+/// # Safety
+/// `key` and `string` must be NULL or valid pointers returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_decrypt(
+    key: *const FfiClientKey,
+    string: *const FheString,
+) -> *mut c_char {
+    catch(|| {
+        let key = key.as_ref()?;
+        let string = string.as_ref()?;
+
+        let plain = key.client_key.decrypt_checked(string).ok()?;
+        CString::new(plain).ok()
+    })
+    .map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Releases a string returned by `fhestring_decrypt`.
+///
+/// # Safety
+/// `string` must be NULL or a pointer returned by `fhestring_decrypt` that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_c_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Releases a string returned by this library.
+///
+/// # Safety
+/// `string` must be NULL or a `FheString` pointer returned by this library that was not freed
+/// yet.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_string_free(string: *mut FheString) {
+    free_handle(string)
+}
+
+/// Decrypts a character returned by an operation into `out`. Returns 0 on success and -1 on
+/// failure.
+///
+/// # Safety
+/// `key` and `char` must be NULL or valid pointers returned by this library, and `out` NULL or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_decrypt_char(
+    key: *const FfiClientKey,
+    char: *const FheAsciiChar,
+    out: *mut u8,
+) -> c_int {
+    let plain = catch(|| {
+        let key = key.as_ref()?;
+        let char = char.as_ref()?;
+
+        Some(key.client_key.decrypt_char(char))
+    });
+
+    match (plain, out.as_mut()) {
+        (Some(plain), Some(out)) => {
+            *out = plain;
+            0
+        }
+        _ => -1,
+    }
+}
+
+/// Releases a character returned by an operation.
+///
+/// # Safety
+/// `char` must be NULL or a `FheAsciiChar` pointer returned by this library that was not freed
+/// yet.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_char_free(char: *mut FheAsciiChar) {
+    free_handle(char)
+}
+
+/// Encrypted 1 if `string` contains `pattern`, otherwise encrypted 0, see `MyServerKey::contains`.
+///
+/// # Safety
+/// `key`, `string` and `pattern` must be NULL or valid pointers returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_contains(
+    key: *const FfiServerKey,
+    string: *const FheString,
+    pattern: *const FheString,
+) -> *mut FheAsciiChar {
+    into_handle(catch(|| {
+        let key = key.as_ref()?;
+        let string = string.as_ref()?;
+        let pattern = pattern.as_ref()?;
+
+        Some(
+            key.server_key
+                .contains(string, &pattern.get_bytes(), &key.public_parameters),
+        )
+    }))
+}
+
+/// The encrypted position of the first occurrence of `pattern` in `string`, see
+/// `MyServerKey::find`.
+///
+/// # Safety
+/// `key`, `string` and `pattern` must be NULL or valid pointers returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_find(
+    key: *const FfiServerKey,
+    string: *const FheString,
+    pattern: *const FheString,
+) -> *mut FheAsciiChar {
+    into_handle(catch(|| {
+        let key = key.as_ref()?;
+        let string = string.as_ref()?;
+        let pattern = pattern.as_ref()?;
+
+        Some(
+            key.server_key
+                .find(string, &pattern.get_bytes(), &key.public_parameters),
+        )
+    }))
+}
+
+/// Replaces every occurrence of `from` in `string` with `to`, see `MyServerKey::replace`.
+///
+/// # Safety
+/// `key`, `string`, `from` and `to` must be NULL or valid pointers returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn fhestring_replace(
+    key: *const FfiServerKey,
+    string: *const FheString,
+    from: *const FheString,
+    to: *const FheString,
+) -> *mut FheString {
+    into_handle(catch(|| {
+        let key = key.as_ref()?;
+        let string = string.as_ref()?;
+        let from = from.as_ref()?;
+        let to = to.as_ref()?;
+
+        Some(key.server_key.replace(
+            string,
+            &from.get_bytes(),
+            &to.get_bytes(),
+            &key.public_parameters,
+        ))
+    }))
+}
@@ -0,0 +1,36 @@
+// A FHE string implementation using tfhe-rs. The `fhestring` binary is a cli on top of this
+// library that runs every algorithm and compares the results with the standard library.
+
+pub use ciphertext::fheasciichar::FheAsciiChar;
+pub use ciphertext::fhestring::FheString;
+pub use ciphertext::public_parameters::PublicParameters;
+pub use client_key::MyClientKey;
+pub use server_key::MyServerKey;
+
+// All algorithms work with unpadded or padded strings
+// Choose your string padding accordingly
+pub const STRING_PADDING: usize = 1;
+
+// This constant represents the upper bound of n given to the repeat algorithm
+// Use a value that is higher than the intended repetitions but note that
+// it increases time complexity of the algorithm in O(n^2)
+pub const MAX_REPETITIONS: usize = 16;
+
+// Max supported value is the maximum u8 value.
+pub const MAX_FIND_LENGTH: usize = 255;
+
+// Tfhe constants to have an 8bit value in our radix ciphertext
+pub const MAX_BLOCKS: usize = 4;
+
+pub mod ciphertext;
+pub mod client_key;
+pub mod ffi;
+pub mod metrics;
+#[cfg(test)]
+mod property_tests;
+pub mod server_key;
+#[cfg(test)]
+mod test;
+pub mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,62 @@
+// Optional timing of the `MyServerKey` methods, compiled in with the `metrics` feature. Every
+// public method starts with `time_method!`, which creates a timer that reports the duration of
+// the call to the registered callback when the method returns. Without the feature the macro
+// expands to nothing, so the methods are unchanged.
+//
+// The tfhe version in use does not expose how many PBS an operation ran, so only the wall clock
+// time is reported. Nested calls are reported separately, `ends_with_clear` for example also
+// reports the `ends_with` it is built on.
+
+#[cfg(feature = "metrics")]
+use std::sync::OnceLock;
+#[cfg(feature = "metrics")]
+use std::time::{Duration, Instant};
+
+// Receives the name of the method and the duration of the call
+#[cfg(feature = "metrics")]
+pub type MetricsCallback = fn(&'static str, Duration);
+
+#[cfg(feature = "metrics")]
+static CALLBACK: OnceLock<MetricsCallback> = OnceLock::new();
+
+// Registers the function that receives the timings, only the first registration is kept
+#[cfg(feature = "metrics")]
+pub fn set_callback(callback: MetricsCallback) {
+    let _ = CALLBACK.set(callback);
+}
+
+// Reports the time between its creation and its drop, so it covers every return path
+#[cfg(feature = "metrics")]
+pub struct MethodTimer {
+    name: &'static str,
+    start: Instant,
+}
+
+#[cfg(feature = "metrics")]
+impl MethodTimer {
+    pub fn start(name: &'static str) -> Self {
+        MethodTimer {
+            name,
+            start: Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Drop for MethodTimer {
+    fn drop(&mut self) {
+        if let Some(callback) = CALLBACK.get() {
+            callback(self.name, self.start.elapsed());
+        }
+    }
+}
+
+// Times the rest of the enclosing method
+macro_rules! time_method {
+    ($name:literal) => {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::MethodTimer::start($name);
+    };
+}
+
+pub(crate) use time_method;
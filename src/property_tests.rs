@@ -0,0 +1,195 @@
+use crate::ciphertext::fhesplit::FheSplit;
+use crate::server_key::MyServerKey;
+use crate::utils::{trim_str_vector, trim_vector};
+use crate::{MyClientKey, PublicParameters, MAX_BLOCKS, MAX_FIND_LENGTH, STRING_PADDING};
+use proptest::prelude::*;
+use std::sync::OnceLock;
+use tfhe::shortint::prelude::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+// The inputs are trivially encrypted to keep the suite fast, this only tests the logic of the
+// algorithms, the tests in src/test.rs use real encryptions
+const CASES: u32 = 32;
+
+// A small alphabet makes it likely that the pattern is actually found in the string
+const STRING_REGEX: &str = "[ab. ]{0,6}";
+const PATTERN_REGEX: &str = "[ab.]{0,2}";
+const NON_EMPTY_PATTERN_REGEX: &str = "[ab.]{1,2}";
+const WHITESPACE_REGEX: &str = "[ab \t\n\r\x0B\x0C]{0,6}";
+
+// Key generation is expensive, share the keys between all cases
+fn keys() -> &'static (MyClientKey, MyServerKey, PublicParameters) {
+    static KEYS: OnceLock<(MyClientKey, MyServerKey, PublicParameters)> = OnceLock::new();
+
+    KEYS.get_or_init(|| {
+        let my_client_key = MyClientKey::from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, MAX_BLOCKS);
+        let my_server_key = my_client_key.get_server_key();
+        let public_parameters = my_client_key.get_public_parameters();
+
+        (my_client_key, my_server_key, public_parameters)
+    })
+}
+
+fn find_result(position: Option<usize>) -> u8 {
+    position.unwrap_or(MAX_FIND_LENGTH) as u8
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    #[test]
+    fn contains(string in STRING_REGEX, pattern in PATTERN_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let needle = my_client_key.encrypt_trivial_no_padding(&pattern);
+
+        let res = my_server_key.contains(&my_string, &needle, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt_char(&res), string.contains(&pattern) as u8);
+    }
+
+    #[test]
+    fn starts_with(string in STRING_REGEX, pattern in PATTERN_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let needle = my_client_key.encrypt_trivial_no_padding(&pattern);
+
+        let res = my_server_key.starts_with(&my_string, &needle, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt_char(&res), string.starts_with(&pattern) as u8);
+    }
+
+    #[test]
+    fn ends_with(string in STRING_REGEX, pattern in PATTERN_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let needle = my_client_key.encrypt_trivial_no_padding(&pattern);
+
+        let res = my_server_key.ends_with(&my_string, &needle, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt_char(&res), string.ends_with(&pattern) as u8);
+    }
+
+    #[test]
+    fn find(string in STRING_REGEX, pattern in PATTERN_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let needle = my_client_key.encrypt_trivial_no_padding(&pattern);
+
+        let res = my_server_key.find(&my_string, &needle, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt_char(&res), find_result(string.find(&pattern)));
+    }
+
+    #[test]
+    fn rfind(string in STRING_REGEX, pattern in PATTERN_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let needle = my_client_key.encrypt_trivial_no_padding(&pattern);
+
+        let res = my_server_key.rfind(my_string, &needle, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt_char(&res), find_result(string.rfind(&pattern)));
+    }
+
+    #[test]
+    fn replace(string in STRING_REGEX, from in NON_EMPTY_PATTERN_REGEX, to in PATTERN_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let from_enc = my_client_key.encrypt_trivial_no_padding(&from);
+        let to_enc = my_client_key.encrypt_trivial_no_padding(&to);
+
+        let res = my_server_key.replace(&my_string, &from_enc, &to_enc, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt(res), string.replace(&from, &to));
+    }
+
+    // Covers an empty `from` and a `to` longer than the string, which exercise the index
+    // arithmetic of both replacement strategies
+    #[test]
+    fn replace_any_lengths(
+        string in STRING_REGEX,
+        from in PATTERN_REGEX,
+        to in "[ab.]{0,8}",
+        padding in 0..3usize
+    ) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, padding);
+        let from_enc = my_client_key.encrypt_trivial_no_padding(&from);
+        let to_enc = my_client_key.encrypt_trivial_no_padding(&to);
+
+        let res = my_server_key.replace(&my_string, &from_enc, &to_enc, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt(res), string.replace(&from, &to));
+    }
+
+    #[test]
+    fn to_upper_to_lower(string in "[a-zA-Z0-9 ]{0,6}") {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+
+        let upper = my_server_key.to_upper(&my_string, public_parameters);
+        let lower = my_server_key.to_lower(&my_string, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt(upper), string.to_uppercase());
+        prop_assert_eq!(my_client_key.decrypt(lower), string.to_lowercase());
+    }
+
+    #[test]
+    fn trim(string in WHITESPACE_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+
+        let trimmed = my_server_key.trim(&my_string, public_parameters);
+        let trimmed_start = my_server_key.trim_start(&my_string, public_parameters);
+        let trimmed_end = my_server_key.trim_end(&my_string, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt(trimmed), string.trim());
+        prop_assert_eq!(my_client_key.decrypt(trimmed_start), string.trim_start());
+        prop_assert_eq!(my_client_key.decrypt(trimmed_end), string.trim_end());
+    }
+
+    #[test]
+    fn comparisons(string in STRING_REGEX, other in STRING_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let other_string = my_client_key.encrypt_trivial_string(&other, STRING_PADDING);
+
+        let eq = my_server_key.eq(&my_string, &other_string, public_parameters);
+        let lt = my_server_key.lt(&my_string, &other_string, public_parameters);
+        let ge = my_server_key.ge(&my_string, &other_string, public_parameters);
+
+        prop_assert_eq!(my_client_key.decrypt_char(&eq), (string == other) as u8);
+        prop_assert_eq!(my_client_key.decrypt_char(&lt), (string < other) as u8);
+        prop_assert_eq!(my_client_key.decrypt_char(&ge), (string >= other) as u8);
+    }
+
+    // Splits are compared without the empty strings at both ends, since the encrypted version
+    // always returns a fixed number of buffers
+    #[test]
+    fn split(string in STRING_REGEX, pattern in NON_EMPTY_PATTERN_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let pattern_enc = my_client_key.encrypt_trivial_no_padding(&pattern);
+
+        let fhe_split = my_server_key.split(&my_string, &pattern_enc, public_parameters);
+        let (plain_split, pattern_found) = FheSplit::decrypt(fhe_split, my_client_key);
+        let expected: Vec<&str> = string.split(pattern.as_str()).collect();
+
+        prop_assert_eq!(trim_vector(plain_split), trim_str_vector(expected));
+        prop_assert_eq!(pattern_found, string.contains(&pattern) as u8);
+    }
+
+    #[test]
+    fn rsplit(string in STRING_REGEX, pattern in NON_EMPTY_PATTERN_REGEX) {
+        let (my_client_key, my_server_key, public_parameters) = keys();
+        let my_string = my_client_key.encrypt_trivial_string(&string, STRING_PADDING);
+        let pattern_enc = my_client_key.encrypt_trivial_no_padding(&pattern);
+
+        let fhe_split = my_server_key.rsplit(&my_string, &pattern_enc, public_parameters);
+        let (plain_split, pattern_found) = FheSplit::decrypt(fhe_split, my_client_key);
+        let expected: Vec<&str> = string.rsplit(pattern.as_str()).collect();
+
+        prop_assert_eq!(trim_vector(plain_split), trim_str_vector(expected));
+        prop_assert_eq!(pattern_found, string.contains(&pattern) as u8);
+    }
+}
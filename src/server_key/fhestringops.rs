@@ -0,0 +1,793 @@
+// Not every forwarded method is used by the binary
+#![allow(dead_code)]
+
+use super::MyServerKey;
+use crate::ciphertext::fheasciichar::FheAsciiChar;
+use crate::ciphertext::fhesplit::FheSplit;
+use crate::ciphertext::fhestring::FheString;
+use crate::ciphertext::fhestrip::FheStrip;
+use crate::ciphertext::public_parameters::PublicParameters;
+use crate::ciphertext::stringops::StringOps;
+use std::ops::Range;
+
+/// Bundles a `MyServerKey` with the `PublicParameters` so that the string methods can be called
+/// without passing the public parameters every time, e.g. `ctx.contains(&string, &needle)`.
+///
+/// Every method forwards to the `MyServerKey` method of the same name.
+#[derive(Clone, Copy)]
+pub struct FheStringOps<'a> {
+    pub server_key: &'a MyServerKey,
+    pub public_parameters: &'a PublicParameters,
+}
+
+impl<'a> FheStringOps<'a> {
+    pub fn new(server_key: &'a MyServerKey, public_parameters: &'a PublicParameters) -> Self {
+        FheStringOps {
+            server_key,
+            public_parameters,
+        }
+    }
+
+    /// Wraps a `FheString` in a `StringOps` carrying this context, so that operators can be used.
+    pub fn wrap(&self, string: FheString) -> StringOps<'a> {
+        StringOps::new(string, self.server_key, self.public_parameters)
+    }
+
+    pub fn to_upper(&self, string: &FheString) -> FheString {
+        self.server_key.to_upper(string, self.public_parameters)
+    }
+
+    pub fn to_upper_mut(&self, string: &mut FheString) {
+        self.server_key.to_upper_mut(string, self.public_parameters)
+    }
+
+    pub fn to_lower(&self, string: &FheString) -> FheString {
+        self.server_key.to_lower(string, self.public_parameters)
+    }
+
+    pub fn to_lower_mut(&self, string: &mut FheString) {
+        self.server_key.to_lower_mut(string, self.public_parameters)
+    }
+
+    pub fn refresh(&self, string: &FheString) -> FheString {
+        self.server_key.refresh(string, self.public_parameters)
+    }
+
+    pub fn contains(&self, string: &FheString, needle: &Vec<FheAsciiChar>) -> FheAsciiChar {
+        self.server_key
+            .contains(string, needle, self.public_parameters)
+    }
+
+    pub fn windowed_eq(&self, string: &FheString, pattern: &[FheAsciiChar]) -> Vec<FheAsciiChar> {
+        self.server_key
+            .windowed_eq(string, pattern, self.public_parameters)
+    }
+
+    pub fn contains_clear(&self, string: &FheString, clear_needle: &str) -> FheAsciiChar {
+        self.server_key
+            .contains_clear(string, clear_needle, self.public_parameters)
+    }
+
+    pub fn contains_clear_opts(
+        &self,
+        string: &FheString,
+        clear_needle: &str,
+        ignore_case: bool,
+    ) -> FheAsciiChar {
+        self.server_key.contains_clear_opts(
+            string,
+            clear_needle,
+            ignore_case,
+            self.public_parameters,
+        )
+    }
+
+    pub fn ends_with(&self, string: &FheString, needle: &Vec<FheAsciiChar>) -> FheAsciiChar {
+        self.server_key
+            .ends_with(string, needle, self.public_parameters)
+    }
+
+    pub fn ends_with_clear(&self, string: &FheString, clear_pattern: &str) -> FheAsciiChar {
+        self.server_key
+            .ends_with_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn starts_with(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheAsciiChar {
+        self.server_key
+            .starts_with(string, pattern, self.public_parameters)
+    }
+
+    pub fn starts_with_clear(&self, string: &FheString, clear_pattern: &str) -> FheAsciiChar {
+        self.server_key
+            .starts_with_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn starts_with_len(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+    ) -> (FheAsciiChar, FheAsciiChar) {
+        self.server_key
+            .starts_with_len(string, pattern, self.public_parameters)
+    }
+
+    pub fn starts_with_any(
+        &self,
+        string: &FheString,
+        prefixes: &[Vec<FheAsciiChar>],
+    ) -> FheAsciiChar {
+        self.server_key
+            .starts_with_any(string, prefixes, self.public_parameters)
+    }
+
+    pub fn match_prefix(&self, string: &FheString, prefixes: &[Vec<FheAsciiChar>]) -> FheAsciiChar {
+        self.server_key
+            .match_prefix(string, prefixes, self.public_parameters)
+    }
+
+    pub fn is_empty(&self, string: &FheString) -> FheAsciiChar {
+        self.server_key.is_empty(string, self.public_parameters)
+    }
+
+    pub fn count_in_set(&self, string: &FheString, set: &[FheAsciiChar]) -> FheAsciiChar {
+        self.server_key
+            .count_in_set(string, set, self.public_parameters)
+    }
+
+    pub fn count_in_set_clear(&self, string: &FheString, clear_set: &str) -> FheAsciiChar {
+        self.server_key
+            .count_in_set_clear(string, clear_set, self.public_parameters)
+    }
+
+    pub fn count_leading(&self, string: &FheString, ch: &FheAsciiChar) -> FheAsciiChar {
+        self.server_key
+            .count_leading(string, ch, self.public_parameters)
+    }
+
+    pub fn count_trailing(&self, string: &FheString, ch: &FheAsciiChar) -> FheAsciiChar {
+        self.server_key
+            .count_trailing(string, ch, self.public_parameters)
+    }
+
+    pub fn checksum(&self, string: &FheString) -> FheAsciiChar {
+        self.server_key.checksum(string, self.public_parameters)
+    }
+
+    pub fn byte_histogram(&self, string: &FheString) -> Vec<FheAsciiChar> {
+        self.server_key
+            .byte_histogram(string, self.public_parameters)
+    }
+
+    pub fn most_frequent_char(&self, string: &FheString) -> (FheAsciiChar, FheAsciiChar) {
+        self.server_key
+            .most_frequent_char(string, self.public_parameters)
+    }
+
+    pub fn char_frequency_rank(&self, string: &FheString, ch: &FheAsciiChar) -> FheAsciiChar {
+        self.server_key
+            .char_frequency_rank(string, ch, self.public_parameters)
+    }
+
+    pub fn run_length_encode(&self, string: &FheString) -> Vec<(FheAsciiChar, FheAsciiChar)> {
+        self.server_key
+            .run_length_encode(string, self.public_parameters)
+    }
+
+    pub fn apply_lut(&self, string: &FheString, table: &[u8; 256]) -> FheString {
+        self.server_key
+            .apply_lut(string, table, self.public_parameters)
+    }
+
+    pub fn translate(
+        &self,
+        string: &FheString,
+        from: &[FheAsciiChar],
+        to: &[FheAsciiChar],
+    ) -> FheString {
+        self.server_key
+            .translate(string, from, to, self.public_parameters)
+    }
+
+    pub fn translate_clear(
+        &self,
+        string: &FheString,
+        clear_from: &str,
+        clear_to: &str,
+    ) -> FheString {
+        self.server_key
+            .translate_clear(string, clear_from, clear_to, self.public_parameters)
+    }
+
+    pub fn title_from_snake_case(&self, string: &FheString) -> FheString {
+        self.server_key
+            .title_from_snake_case(string, self.public_parameters)
+    }
+
+    pub fn retain(
+        &self,
+        string: &FheString,
+        keep: impl Fn(&FheAsciiChar) -> FheAsciiChar,
+    ) -> FheString {
+        self.server_key.retain(string, keep, self.public_parameters)
+    }
+
+    pub fn remove_matching(
+        &self,
+        string: &FheString,
+        remove: impl Fn(&FheAsciiChar) -> FheAsciiChar,
+    ) -> FheString {
+        self.server_key
+            .remove_matching(string, remove, self.public_parameters)
+    }
+
+    pub fn mask(&self, string: &FheString, keep: &[bool]) -> FheString {
+        self.server_key.mask(string, keep, self.public_parameters)
+    }
+
+    pub fn mask_fill(&self, string: &FheString, keep: &[bool], fill: &FheAsciiChar) -> FheString {
+        self.server_key
+            .mask_fill(string, keep, fill, self.public_parameters)
+    }
+
+    pub fn redact_digits(&self, string: &FheString, mask: &FheAsciiChar) -> FheString {
+        self.server_key
+            .redact_digits(string, mask, self.public_parameters)
+    }
+
+    pub fn keep_last_n(&self, string: &FheString, n: usize, mask: &FheAsciiChar) -> FheString {
+        self.server_key
+            .keep_last_n(string, n, mask, self.public_parameters)
+    }
+
+    pub fn dedup(&self, string: &FheString) -> FheString {
+        self.server_key.dedup(string, self.public_parameters)
+    }
+
+    pub fn is_numeric_string(&self, string: &FheString) -> FheAsciiChar {
+        self.server_key
+            .is_numeric_string(string, self.public_parameters)
+    }
+
+    pub fn is_alphanumeric_string(&self, string: &FheString) -> FheAsciiChar {
+        self.server_key
+            .is_alphanumeric_string(string, self.public_parameters)
+    }
+
+    pub fn looks_like_email(&self, string: &FheString) -> FheAsciiChar {
+        self.server_key
+            .looks_like_email(string, self.public_parameters)
+    }
+
+    pub fn is_ascii(&self, string: &FheString) -> FheAsciiChar {
+        self.server_key.is_ascii(string, self.public_parameters)
+    }
+
+    pub fn is_sorted(&self, string: &FheString) -> FheAsciiChar {
+        self.server_key.is_sorted(string, self.public_parameters)
+    }
+
+    pub fn len(&self, string: &FheString) -> FheAsciiChar {
+        self.server_key.len(string, self.public_parameters)
+    }
+
+    pub fn char_at(&self, string: &FheString, index: &FheAsciiChar) -> FheAsciiChar {
+        self.server_key
+            .char_at(string, index, self.public_parameters)
+    }
+
+    pub fn substring(&self, string: &FheString, start: usize, end: usize) -> FheString {
+        self.server_key.substring(string, start, end)
+    }
+
+    pub fn substring_enc(
+        &self,
+        string: &FheString,
+        start: &FheAsciiChar,
+        len: &FheAsciiChar,
+    ) -> FheString {
+        self.server_key
+            .substring_enc(string, start, len, self.public_parameters)
+    }
+
+    pub fn repeat_clear(&self, string: &FheString, repetitions: usize) -> FheString {
+        self.server_key
+            .repeat_clear(string, repetitions, self.public_parameters)
+    }
+
+    pub fn pad_to_block_multiple(
+        &self,
+        string: &FheString,
+        block: usize,
+        fill: FheAsciiChar,
+    ) -> FheString {
+        self.server_key
+            .pad_to_block_multiple(string, block, fill, self.public_parameters)
+    }
+
+    pub fn zfill_signed(&self, string: &FheString, width: usize) -> FheString {
+        self.server_key
+            .zfill_signed(string, width, self.public_parameters)
+    }
+
+    pub fn repeat(&self, string: &FheString, repetitions: FheAsciiChar) -> FheString {
+        self.server_key
+            .repeat(string, repetitions, self.public_parameters)
+    }
+
+    pub fn repeat_checked(
+        &self,
+        string: &FheString,
+        repetitions: FheAsciiChar,
+    ) -> (FheString, FheAsciiChar) {
+        self.server_key
+            .repeat_checked(string, repetitions, self.public_parameters)
+    }
+
+    pub fn replace(
+        &self,
+        string: &FheString,
+        from: &Vec<FheAsciiChar>,
+        to: &Vec<FheAsciiChar>,
+    ) -> FheString {
+        self.server_key
+            .replace(string, from, to, self.public_parameters)
+    }
+
+    pub fn replace_clear(&self, string: &FheString, clear_from: &str, clear_to: &str) -> FheString {
+        self.server_key
+            .replace_clear(string, clear_from, clear_to, self.public_parameters)
+    }
+
+    pub fn replace_first(
+        &self,
+        string: &FheString,
+        from: &Vec<FheAsciiChar>,
+        to: &Vec<FheAsciiChar>,
+    ) -> FheString {
+        self.server_key
+            .replace_first(string, from, to, self.public_parameters)
+    }
+
+    pub fn replace_first_clear(
+        &self,
+        string: &FheString,
+        clear_from: &str,
+        clear_to: &str,
+    ) -> FheString {
+        self.server_key
+            .replace_first_clear(string, clear_from, clear_to, self.public_parameters)
+    }
+
+    pub fn replace_range(
+        &self,
+        string: &FheString,
+        range: Range<usize>,
+        replacement: &Vec<FheAsciiChar>,
+    ) -> FheString {
+        self.server_key
+            .replace_range(string, range, replacement, self.public_parameters)
+    }
+
+    pub fn rfind(&self, string: FheString, pattern: &Vec<FheAsciiChar>) -> FheAsciiChar {
+        self.server_key
+            .rfind(string, pattern, self.public_parameters)
+    }
+
+    pub fn rfind_clear(&self, string: &FheString, clear_pattern: &str) -> FheAsciiChar {
+        self.server_key
+            .rfind_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn find(&self, string: &FheString, pattern: &Vec<FheAsciiChar>) -> FheAsciiChar {
+        self.server_key
+            .find(string, pattern, self.public_parameters)
+    }
+
+    pub fn find_clear(&self, string: &FheString, clear_pattern: &str) -> FheAsciiChar {
+        self.server_key
+            .find_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn find_rk(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheAsciiChar {
+        self.server_key
+            .find_rk(string, pattern, self.public_parameters)
+    }
+
+    pub fn find_nth(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        n: &FheAsciiChar,
+    ) -> FheAsciiChar {
+        self.server_key
+            .find_nth(string, pattern, n, self.public_parameters)
+    }
+
+    pub fn matches_count(&self, string: &FheString, needle: &[FheAsciiChar]) -> FheAsciiChar {
+        self.server_key
+            .matches_count(string, needle, self.public_parameters)
+    }
+
+    pub fn matches_count_clear(&self, string: &FheString, clear_needle: &str) -> FheAsciiChar {
+        self.server_key
+            .matches_count_clear(string, clear_needle, self.public_parameters)
+    }
+
+    pub fn contains_with_position(
+        &self,
+        string: &FheString,
+        pattern: &Vec<FheAsciiChar>,
+    ) -> (FheAsciiChar, FheAsciiChar) {
+        self.server_key
+            .contains_with_position(string, pattern, self.public_parameters)
+    }
+
+    pub fn contains_with_position_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+    ) -> (FheAsciiChar, FheAsciiChar) {
+        self.server_key
+            .contains_with_position_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn eq(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key.eq(string, other, self.public_parameters)
+    }
+
+    pub fn ne(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key.ne(string, other, self.public_parameters)
+    }
+
+    pub fn equals_any(&self, string: &FheString, candidates: &[FheString]) -> FheAsciiChar {
+        self.server_key
+            .equals_any(string, candidates, self.public_parameters)
+    }
+
+    pub fn which_equals(&self, string: &FheString, candidates: &[FheString]) -> FheAsciiChar {
+        self.server_key
+            .which_equals(string, candidates, self.public_parameters)
+    }
+
+    pub fn diff_positions(&self, string: &FheString, other: &FheString) -> Vec<FheAsciiChar> {
+        self.server_key.diff_positions(string, other)
+    }
+
+    pub fn common_prefix_len(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key
+            .common_prefix_len(string, other, self.public_parameters)
+    }
+
+    pub fn common_suffix_len(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key
+            .common_suffix_len(string, other, self.public_parameters)
+    }
+
+    pub fn eq_ignore_case(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key
+            .eq_ignore_case(string, other, self.public_parameters)
+    }
+
+    pub fn eq_ignore_case_clear(&self, string: &FheString, clear_other: &str) -> FheAsciiChar {
+        self.server_key
+            .eq_ignore_case_clear(string, clear_other, self.public_parameters)
+    }
+
+    pub fn eq_trimmed(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key
+            .eq_trimmed(string, other, self.public_parameters)
+    }
+
+    pub fn strip_prefix(&self, string: &FheString, pattern: &Vec<FheAsciiChar>) -> FheStrip {
+        self.server_key
+            .strip_prefix(string, pattern, self.public_parameters)
+    }
+
+    pub fn strip_suffix(&self, string: FheString, needle: &Vec<FheAsciiChar>) -> FheStrip {
+        self.server_key
+            .strip_suffix(string, needle, self.public_parameters)
+    }
+
+    pub fn strip_prefix_clear(&self, string: &FheString, clear_pattern: &str) -> FheStrip {
+        self.server_key
+            .strip_prefix_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn strip_suffix_clear(&self, string: &FheString, clear_pattern: &str) -> FheStrip {
+        self.server_key
+            .strip_suffix_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn lt(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key.lt(string, other, self.public_parameters)
+    }
+
+    pub fn le(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key.le(string, other, self.public_parameters)
+    }
+
+    pub fn gt(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key.gt(string, other, self.public_parameters)
+    }
+
+    pub fn ge(&self, string: &FheString, other: &FheString) -> FheAsciiChar {
+        self.server_key.ge(string, other, self.public_parameters)
+    }
+
+    pub fn replacen(
+        &self,
+        string: &FheString,
+        from: &Vec<FheAsciiChar>,
+        to: &Vec<FheAsciiChar>,
+        n: FheAsciiChar,
+    ) -> FheString {
+        self.server_key
+            .replacen(string, from, to, n, self.public_parameters)
+    }
+
+    pub fn replacen_clear(
+        &self,
+        string: &FheString,
+        from_clear: &str,
+        to_clear: &str,
+        n_clear: u8,
+    ) -> FheString {
+        self.server_key.replacen_clear(
+            string,
+            from_clear,
+            to_clear,
+            n_clear,
+            self.public_parameters,
+        )
+    }
+
+    pub fn concatenate(&self, string: &FheString, other: &FheString) -> FheString {
+        self.server_key
+            .concatenate(string, other, self.public_parameters)
+    }
+
+    pub fn interleave(&self, string: &FheString, other: &FheString) -> FheString {
+        self.server_key
+            .interleave(string, other, self.public_parameters)
+    }
+
+    pub fn deinterleave(&self, string: &FheString) -> (FheString, FheString) {
+        self.server_key.deinterleave(string, self.public_parameters)
+    }
+
+    pub fn rotate_left(&self, string: &FheString, k: &FheAsciiChar) -> FheString {
+        self.server_key
+            .rotate_left(string, k, self.public_parameters)
+    }
+
+    pub fn rotate_right(&self, string: &FheString, k: &FheAsciiChar) -> FheString {
+        self.server_key
+            .rotate_right(string, k, self.public_parameters)
+    }
+
+    pub fn trim_end(&self, string: &FheString) -> FheString {
+        self.server_key.trim_end(string, self.public_parameters)
+    }
+
+    pub fn trim_start(&self, string: &FheString) -> FheString {
+        self.server_key.trim_start(string, self.public_parameters)
+    }
+
+    pub fn trim(&self, string: &FheString) -> FheString {
+        self.server_key.trim(string, self.public_parameters)
+    }
+
+    pub fn trim_end_counted(&self, string: &FheString) -> (FheString, FheAsciiChar) {
+        self.server_key
+            .trim_end_counted(string, self.public_parameters)
+    }
+
+    pub fn trim_start_counted(&self, string: &FheString) -> (FheString, FheAsciiChar) {
+        self.server_key
+            .trim_start_counted(string, self.public_parameters)
+    }
+
+    pub fn strip_leading(&self, string: &FheString, ch: &FheAsciiChar) -> FheString {
+        self.server_key
+            .strip_leading(string, ch, self.public_parameters)
+    }
+
+    pub fn strip_trailing(&self, string: &FheString, ch: &FheAsciiChar) -> FheString {
+        self.server_key
+            .strip_trailing(string, ch, self.public_parameters)
+    }
+
+    pub fn trim_start_matches(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheString {
+        self.server_key
+            .trim_start_matches(string, pattern, self.public_parameters)
+    }
+
+    pub fn trim_start_matches_clear(&self, string: &FheString, clear_pattern: &str) -> FheString {
+        self.server_key
+            .trim_start_matches_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn trim_end_matches(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheString {
+        self.server_key
+            .trim_end_matches(string, pattern, self.public_parameters)
+    }
+
+    pub fn trim_end_matches_clear(&self, string: &FheString, clear_pattern: &str) -> FheString {
+        self.server_key
+            .trim_end_matches_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn trim_indices(&self, string: &FheString) -> (FheAsciiChar, FheAsciiChar) {
+        self.server_key.trim_indices(string, self.public_parameters)
+    }
+
+    pub fn ltrim(&self, string: &FheString) -> FheString {
+        self.server_key.ltrim(string, self.public_parameters)
+    }
+
+    pub fn rtrim(&self, string: &FheString) -> FheString {
+        self.server_key.rtrim(string, self.public_parameters)
+    }
+
+    pub fn rsplit(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheSplit {
+        self.server_key
+            .rsplit(string, pattern, self.public_parameters)
+    }
+
+    pub fn rsplit_clear(&self, string: &FheString, clear_pattern: &str) -> FheSplit {
+        self.server_key
+            .rsplit_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn rsplitn(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        n: FheAsciiChar,
+    ) -> FheSplit {
+        self.server_key
+            .rsplitn(string, pattern, n, self.public_parameters)
+    }
+
+    pub fn rsplitn_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        clear_n: usize,
+    ) -> FheSplit {
+        self.server_key
+            .rsplitn_clear(string, clear_pattern, clear_n, self.public_parameters)
+    }
+
+    pub fn rsplit_once(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheSplit {
+        self.server_key
+            .rsplit_once(string, pattern, self.public_parameters)
+    }
+
+    pub fn rsplit_once_clear(&self, string: &FheString, clear_pattern: &str) -> FheSplit {
+        self.server_key
+            .rsplit_once_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn rsplit_terminator(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheSplit {
+        self.server_key
+            .rsplit_terminator(string, pattern, self.public_parameters)
+    }
+
+    pub fn rsplit_terminator_clear(&self, string: &FheString, clear_pattern: &str) -> FheSplit {
+        self.server_key
+            .rsplit_terminator_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn split(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheSplit {
+        self.server_key
+            .split(string, pattern, self.public_parameters)
+    }
+
+    pub fn split_clear(&self, string: &FheString, clear_pattern: &str) -> FheSplit {
+        self.server_key
+            .split_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn split_with_positions(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+    ) -> (FheSplit, Vec<FheAsciiChar>) {
+        self.server_key
+            .split_with_positions(string, pattern, self.public_parameters)
+    }
+
+    pub fn split_with_positions_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+    ) -> (FheSplit, Vec<FheAsciiChar>) {
+        self.server_key
+            .split_with_positions_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn split_inclusive(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheSplit {
+        self.server_key
+            .split_inclusive(string, pattern, self.public_parameters)
+    }
+
+    pub fn split_inclusive_clear(&self, string: &FheString, clear_pattern: &str) -> FheSplit {
+        self.server_key
+            .split_inclusive_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn split_terminator(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheSplit {
+        self.server_key
+            .split_terminator(string, pattern, self.public_parameters)
+    }
+
+    pub fn split_terminator_clear(&self, string: &FheString, clear_pattern: &str) -> FheSplit {
+        self.server_key
+            .split_terminator_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn split_ascii_whitespace(&self, string: &FheString) -> FheSplit {
+        self.server_key
+            .split_ascii_whitespace(string, self.public_parameters)
+    }
+
+    pub fn reverse_words(&self, string: &FheString) -> FheString {
+        self.server_key
+            .reverse_words(string, self.public_parameters)
+    }
+
+    pub fn splitn(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        n: FheAsciiChar,
+    ) -> FheSplit {
+        self.server_key
+            .splitn(string, pattern, n, self.public_parameters)
+    }
+
+    pub fn splitn_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        clear_n: usize,
+    ) -> FheSplit {
+        self.server_key
+            .splitn_clear(string, clear_pattern, clear_n, self.public_parameters)
+    }
+
+    pub fn split_once(&self, string: &FheString, pattern: &[FheAsciiChar]) -> FheSplit {
+        self.server_key
+            .split_once(string, pattern, self.public_parameters)
+    }
+
+    pub fn split_once_clear(&self, string: &FheString, clear_pattern: &str) -> FheSplit {
+        self.server_key
+            .split_once_clear(string, clear_pattern, self.public_parameters)
+    }
+
+    pub fn rejoin(&self, fhe_split: &FheSplit, separator: &[FheAsciiChar]) -> FheString {
+        fhe_split.rejoin(separator, self.server_key, self.public_parameters)
+    }
+
+    pub fn truncate_at_char(&self, string: &FheString, delim: &FheAsciiChar) -> FheString {
+        self.server_key
+            .truncate_at_char(string, delim, self.public_parameters)
+    }
+
+    pub fn after_char(&self, string: &FheString, delim: &FheAsciiChar) -> FheStrip {
+        self.server_key
+            .after_char(string, delim, self.public_parameters)
+    }
+
+    pub fn split_bounded(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        max_segments: usize,
+    ) -> FheSplit {
+        self.server_key
+            .split_bounded(string, pattern, max_segments, self.public_parameters)
+    }
+}
@@ -3,19 +3,33 @@ use crate::ciphertext::fhestring::{Comparison, FheString};
 use crate::ciphertext::fhestrip::FheStrip;
 use crate::ciphertext::public_parameters::PublicParameters;
 use crate::client_key::MyClientKey;
+use crate::metrics::time_method;
 use crate::utils::{self, abs_difference};
 use crate::{MAX_FIND_LENGTH, MAX_REPETITIONS};
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
+pub mod fhestringops;
 pub mod split;
 pub mod trim;
 
+// How many occurrences of the pattern the replace helpers replace
+enum ReplaceCount {
+    All,
+    First,
+    AtMost(FheAsciiChar),
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MyServerKey {
     pub key: tfhe::integer::ServerKey,
 }
 
 impl MyServerKey {
+    /// The maximum number of copies `repeat` and `repeat_clear` produce, larger repetitions are
+    /// truncated to it.
+    pub const MAX_REPETITIONS: usize = MAX_REPETITIONS;
+
     /// Creates a new `MyServerKey` instance from a given `ServerKey`.
     ///
     /// # Arguments
@@ -36,6 +50,7 @@ impl MyServerKey {
     /// `MyServerKey` - A new `MyServerKey` instance constructed from the server key derived from
     ///  `my_client_key`.
     pub fn _from_client_key(my_client_key: MyClientKey) -> Self {
+        time_method!("_from_client_key");
         my_client_key.get_server_key()
     }
 
@@ -63,14 +78,13 @@ impl MyServerKey {
     /// assert_eq!(actual, "ZAMA IS AWESOME");
     /// ```
     pub fn to_upper(&self, string: &FheString, public_parameters: &PublicParameters) -> FheString {
+        time_method!("to_upper");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
         let bytes = string
             .iter()
             .map(|b| {
-                let is_not_lowercase = b
-                    .is_lowercase(&self.key, public_parameters)
-                    .flip(&self.key, public_parameters);
+                let is_not_lowercase = b.is_lowercase(&self.key).flip(&self.key, public_parameters);
                 b.sub(
                     &self.key,
                     &is_not_lowercase.if_then_else(&self.key, &zero, &string.get_cst()),
@@ -108,14 +122,13 @@ impl MyServerKey {
     /// assert_eq!(actual, "zama is awesome");
     /// ```
     pub fn to_lower(&self, string: &FheString, public_parameters: &PublicParameters) -> FheString {
+        time_method!("to_lower");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
         let bytes = string
             .iter()
             .map(|b| {
-                let is_not_uppercase = b
-                    .is_uppercase(&self.key, public_parameters)
-                    .flip(&self.key, public_parameters);
+                let is_not_uppercase = b.is_uppercase(&self.key).flip(&self.key, public_parameters);
                 b.add(
                     &self.key,
                     &is_not_uppercase.if_then_else(&self.key, &zero, &string.get_cst()),
@@ -127,6 +140,111 @@ impl MyServerKey {
         FheString::new(bytes, cst)
     }
 
+    /// Converts all lowercase characters in a given `FheString` to uppercase, in place.
+    ///
+    /// Same as `to_upper` but modifies the string instead of allocating a new one.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "zama IS awesome";
+    ///
+    /// let mut my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// my_server_key.to_upper_mut(&mut my_string, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_string);
+    ///
+    /// assert_eq!(actual, "ZAMA IS AWESOME");
+    /// ```
+    pub fn to_upper_mut(&self, string: &mut FheString, public_parameters: &PublicParameters) {
+        time_method!("to_upper_mut");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let cst = string.get_cst();
+
+        for b in string.iter_mut() {
+            let is_not_lowercase = b.is_lowercase(&self.key).flip(&self.key, public_parameters);
+            *b = b.sub(
+                &self.key,
+                &is_not_lowercase.if_then_else(&self.key, &zero, &cst),
+            );
+        }
+    }
+
+    /// Converts all uppercase characters in a given `FheString` to lowercase, in place.
+    ///
+    /// Same as `to_lower` but modifies the string instead of allocating a new one.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "zama IS awesome";
+    ///
+    /// let mut my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// my_server_key.to_lower_mut(&mut my_string, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_string);
+    ///
+    /// assert_eq!(actual, "zama is awesome");
+    /// ```
+    pub fn to_lower_mut(&self, string: &mut FheString, public_parameters: &PublicParameters) {
+        time_method!("to_lower_mut");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let cst = string.get_cst();
+
+        for b in string.iter_mut() {
+            let is_not_uppercase = b.is_uppercase(&self.key).flip(&self.key, public_parameters);
+            *b = b.add(
+                &self.key,
+                &is_not_uppercase.if_then_else(&self.key, &zero, &cst),
+            );
+        }
+    }
+
+    /// Refreshes every character of a given `FheString` with a bootstrap.
+    ///
+    /// Each operation adds some noise to the ciphertexts, and too much noise makes decryption
+    /// fail. The bootstrap resets the noise without changing the characters, so this can be used
+    /// between the steps of a long pipeline of operations. It costs one bootstrap per block of
+    /// every character, padding included.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to refresh.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The same string with fresh noise.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "zama";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let my_new_string = my_server_key.refresh(&my_string, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_new_string);
+    ///
+    /// assert_eq!(actual, "zama");
+    /// ```
+    pub fn refresh(&self, string: &FheString, public_parameters: &PublicParameters) -> FheString {
+        time_method!("refresh");
+        let bytes = string
+            .iter()
+            .map(|b| b.refresh(&self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
     /// Checks if a given `FheString` contains a specified pattern.
     ///
     /// # Arguments
@@ -154,31 +272,136 @@ impl MyServerKey {
         needle: &Vec<FheAsciiChar>,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
-        if string.is_empty() && needle.is_empty() {
-            return FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
-        }
-        let mut result = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        time_method!("contains");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        self.pattern_match_flags(string, needle, public_parameters)
+            .iter()
+            .fold(zero, |result, is_match| result.bitor(&self.key, is_match))
+    }
+
+    /// Compares a pattern with a given `FheString` at every start offset.
+    ///
+    /// These are the raw flags that `contains` ORs and `find` selects the first of, exposed so
+    /// that other matching logic like counting or masking can be built on top of them without
+    /// scanning the string again. The padding is \0 so a non empty pattern never matches
+    /// there, an empty pattern matches at every offset including the padding.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search within.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to compare.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `Vec<FheAsciiChar>` - For every offset at which the pattern fits in the string, encrypted
+    /// 1 if it matches there, otherwise encrypted 0. Empty if the pattern is longer than the
+    /// string.
+    ///
+    /// # Example
+    /// ```
+    /// let heistack_plain = "ababa";
+    /// let needle_plain = "aba";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
+    /// let flags = my_server_key.windowed_eq(&heistack, &needle, &public_parameters);
+    /// let dec = flags
+    ///     .iter()
+    ///     .map(|flag| my_client_key.decrypt_char(flag))
+    ///     .collect::<Vec<u8>>();
+    ///
+    /// assert_eq!(dec, vec![1u8, 0, 1, 0]);
+    /// ```
+    pub fn windowed_eq(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> Vec<FheAsciiChar> {
+        time_method!("windowed_eq");
+        self.pattern_match_flags(string, pattern, public_parameters)
+    }
+
+    // The match scan shared by the search methods, the kth flag is set if the pattern matches
+    // at offset k. Nothing is masked, the \0 padding never equals a pattern character so only
+    // an empty pattern matches there, the callers handle that case if they need to
+    fn pattern_match_flags(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> Vec<FheAsciiChar> {
         let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
-        let end = string.len().checked_sub(needle.len());
 
-        match end {
-            Some(end_of_pattern) => {
-                // If pattern and string have the same size and are equal
-                // this is needed to actually iterate the loop
-                // let end_of_pattern = utils::adjust_end_of_pattern(end_of_pattern);
+        let Some(end_of_pattern) = string.len().checked_sub(pattern.len()) else {
+            return vec![];
+        };
 
-                for i in 0..=end_of_pattern {
-                    let mut current_result = one.clone();
-                    for (j, needle_char) in needle.iter().enumerate() {
-                        let eql = string[i + j].eq(&self.key, needle_char);
-                        current_result = current_result.bitand(&self.key, &eql);
-                    }
-                    result = result.bitor(&self.key, &current_result);
+        (0..=end_of_pattern)
+            .map(|i| {
+                pattern
+                    .iter()
+                    .enumerate()
+                    .fold(one.clone(), |is_match, (j, pattern_char)| {
+                        is_match.bitand(&self.key, &string[i + j].eq(&self.key, pattern_char))
+                    })
+            })
+            .collect()
+    }
+
+    // For every starting position of the string, encrypted 1 if a match of `pattern` starts there
+    // and does not overlap an earlier match, scanning from the left like `str::match_indices`.
+    // The empty pattern matches at every position up to the length, including the padding
+    fn non_overlapping_match_flags(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> Vec<FheAsciiChar> {
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let skip_after_match = FheAsciiChar::encrypt_trivial(
+            pattern.len().saturating_sub(1) as u8,
+            public_parameters,
+            &self.key,
+        );
+
+        let len = pattern
+            .is_empty()
+            .then(|| self.len(string, public_parameters));
+
+        // Number of positions still covered by the previous match
+        let mut skip = zero.clone();
+
+        self.pattern_match_flags(string, pattern, public_parameters)
+            .into_iter()
+            .enumerate()
+            .map(|(i, flag)| {
+                let mut is_match = flag.bitand(&self.key, &skip.eq(&self.key, &zero));
+                if let Some(len) = &len {
+                    let enc_i =
+                        FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
+                    is_match = is_match.bitand(&self.key, &enc_i.le(&self.key, len));
                 }
-                result
-            }
-            None => FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key),
-        }
+
+                skip = is_match.if_then_else(
+                    &self.key,
+                    &skip_after_match,
+                    &skip.ne(&self.key, &zero).if_then_else(
+                        &self.key,
+                        &skip.sub(&self.key, &one),
+                        &zero,
+                    ),
+                );
+
+                is_match
+            })
+            .collect()
     }
 
     /// Checks if a given `FheString` contains a specified plaintext pattern.
@@ -201,6 +424,7 @@ impl MyServerKey {
         clear_needle: &str,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("contains_clear");
         let needle = clear_needle
             .as_bytes()
             .iter()
@@ -210,12 +434,67 @@ impl MyServerKey {
         self.contains(string, &needle, public_parameters)
     }
 
+    /// Checks if a given `FheString` contains a specified plaintext pattern, optionally ignoring
+    /// case.
+    ///
+    /// Same as `contains_clear` when `ignore_case` is false. Otherwise the string is lowercased
+    /// once and compared against the lowercased plaintext pattern, which is cheaper than calling
+    /// `to_lower` on both sides.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search in.
+    /// * `clear_needle`: &str - The plaintext pattern to search for.
+    /// * `ignore_case`: bool - Whether ASCII case should be ignored.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - Encrypted 1 if the pattern is found, otherwise encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "Hello";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.contains_clear_opts(&heistack, "hello", true, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
+    /// ```
+    pub fn contains_clear_opts(
+        &self,
+        string: &FheString,
+        clear_needle: &str,
+        ignore_case: bool,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("contains_clear_opts");
+        if !ignore_case {
+            return self.contains_clear(string, clear_needle, public_parameters);
+        }
+
+        let string_lowercase = self.to_lower(string, public_parameters);
+        self.contains_clear(
+            &string_lowercase,
+            &clear_needle.to_ascii_lowercase(),
+            public_parameters,
+        )
+    }
+
     /// Checks if a given `FheString` ends with a specified pattern, considering padding.
     ///
+    /// The content is shifted right over the padding once, in O(n log n), so that it ends at the
+    /// end of the buffer, and the pattern is only compared against the last characters. The
+    /// content ends before position 256, so a longer buffer is cut there first.
+    ///
     /// # Arguments
     /// * `string`: &FheString - The string to check.
-    /// * `pattern`: &Vec<FheAsciiChar> - The unpadded pattern to compare against.
-    /// * `padding`: usize - The padding size to consider at the end of the string.
+    /// * `needle`: &Vec<FheAsciiChar> - The unpadded pattern to compare against.
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
@@ -244,40 +523,37 @@ impl MyServerKey {
         needle: &Vec<FheAsciiChar>,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
-        if string.is_empty() && needle.is_empty() {
+        time_method!("ends_with");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        if needle.is_empty() {
             return FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
         }
-        let mut result = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
-        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
-        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
-        let end = string.len().checked_sub(needle.len());
+        // An encrypted length can't reach past 255, so neither can the content
+        let capacity = usize::min(string.len(), u8::MAX as usize);
+        if needle.len() > capacity {
+            return zero;
+        }
 
-        match end {
-            Some(end_of_pattern) => {
-                for i in 0..=end_of_pattern {
-                    let mut current_result = one.clone();
-                    let mut are_all_comparison_chars_non_zero = one.clone();
+        let string_len = self.len(string, public_parameters);
+        let enc_capacity =
+            FheAsciiChar::encrypt_trivial(capacity as u8, public_parameters, &self.key);
+        let padding_len = enc_capacity.sub(&self.key, &string_len);
 
-                    for (j, needle_char) in needle.iter().enumerate() {
-                        let eql = string[i + j].eq(&self.key, needle_char);
-                        current_result = current_result.bitand(&self.key, &eql);
+        // Past the start of the content the shifted characters are \0, which never match
+        let aligned = self.shift_right(
+            string[..capacity].to_vec(),
+            &padding_len,
+            capacity,
+            public_parameters,
+        );
 
-                        // If we encounter padding we should ignore the result
-                        let is_char_not_zero = string[i + j].ne(&self.key, &zero);
-                        are_all_comparison_chars_non_zero =
-                            are_all_comparison_chars_non_zero.bitand(&self.key, &is_char_not_zero);
-                    }
-                    // Use the last result that has not encrountered padding
-                    result = are_all_comparison_chars_non_zero.if_then_else(
-                        &self.key,
-                        &current_result,
-                        &result,
-                    );
-                }
-                result
-            }
-            None => FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key),
+        let mut result = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        for (string_char, needle_char) in aligned[capacity - needle.len()..].iter().zip(needle) {
+            result = result.bitand(&self.key, &string_char.eq(&self.key, needle_char));
         }
+
+        result
     }
 
     /// Checks if a given `FheString` ends with a specified plaintext pattern, considering padding.
@@ -306,6 +582,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("ends_with_clear");
         let pattern = clear_pattern
             .as_bytes()
             .iter()
@@ -347,6 +624,7 @@ impl MyServerKey {
         pattern: &[FheAsciiChar],
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("starts_with");
         let mut result = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
         let end_of_pattern = std::cmp::min(pattern.len(), string.len());
 
@@ -395,6 +673,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("starts_with_clear");
         let pattern = clear_pattern
             .as_bytes()
             .iter()
@@ -403,154 +682,212 @@ impl MyServerKey {
         self.starts_with(string, &pattern, public_parameters)
     }
 
-    /// Checks if a given `FheString` is empty.
+    /// Checks if a given `FheString` starts with a specified pattern, and returns the length of
+    /// the match.
+    ///
+    /// A lighter alternative to `strip_prefix` for callers that only need to know how many
+    /// characters to skip, the string itself is not shifted.
     ///
     /// # Arguments
     /// * `string`: &FheString - The string to check.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to look for at the start.
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
-    /// `FheAsciiChar` - Encrypted 1 if the string is empty, otherwise encrypted 0.
+    /// `(FheAsciiChar, FheAsciiChar)` - Encrypted 1 if the string starts with the pattern,
+    /// otherwise encrypted 0, and the encrypted length of the pattern if it matched, otherwise
+    /// encrypted 0.
     ///
     /// # Example:
     /// ```
-    /// let my_string_plain = "";
+    /// let heistack_plain = "hello world";
+    /// let needle_plain = "hello";
     ///
-    /// let my_string = my_client_key.encrypt(
-    ///     my_string_plain,
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
     ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
+    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
     ///
-    /// let res = my_server_key.is_empty(&my_string, &public_parameters);
-    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    /// let (matched, consumed) =
+    ///     my_server_key.starts_with_len(&heistack, &needle, &public_parameters);
     ///
-    /// assert_eq!(dec, 1u8);
+    /// assert_eq!(my_client_key.decrypt_char(&matched), 1u8);
+    /// assert_eq!(my_client_key.decrypt_char(&consumed), 5u8);
     /// ```
-    pub fn is_empty(
+    pub fn starts_with_len(
         &self,
         string: &FheString,
+        pattern: &[FheAsciiChar],
         public_parameters: &PublicParameters,
-    ) -> FheAsciiChar {
+    ) -> (FheAsciiChar, FheAsciiChar) {
+        time_method!("starts_with_len");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
-        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let pattern_len =
+            FheAsciiChar::encrypt_trivial(pattern.len() as u8, public_parameters, &self.key);
 
-        if string.is_empty() {
-            return one;
-        }
-
-        let mut result = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
-
-        for i in 0..string.len() {
-            let eql = string[i].eq(&self.key, &zero);
-            result = result.bitand(&self.key, &eql);
-        }
+        let matched = self.starts_with(string, pattern, public_parameters);
+        let consumed = matched.if_then_else(&self.key, &pattern_len, &zero);
 
-        result
+        (matched, consumed)
     }
 
-    /// Computes the length of a given `FheString`.
+    /// Checks if a given `FheString` starts with any of several patterns.
+    ///
+    /// The string is read once, every character is compared with the character at the same
+    /// position of every pattern that is long enough, so there is a single pass for all the
+    /// patterns instead of one `starts_with` each. Useful to dispatch on an encrypted protocol
+    /// verb.
     ///
     /// # Arguments
-    /// * `string`: &FheString - The string whose length is to be computed.
+    /// * `string`: &FheString - The string to check.
+    /// * `prefixes`: &[Vec<FheAsciiChar>] - The unpadded patterns to compare against.
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
-    /// `FheAsciiChar` - The encrypted length of the string, without the padding
+    /// `FheAsciiChar` - Encrypted 1 if the string starts with one of the patterns, otherwise
+    /// encrypted 0.
     ///
-    /// # Example:
+    /// # Example
     /// ```
-    /// let my_string_plain = "hello world";
+    /// let heistack_plain = "POST /x";
     ///
-    /// let my_string = my_client_key.encrypt(
-    ///     my_string_plain,
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
     ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
-    ///
-    /// let res = my_server_key.len(&my_string, &public_parameters);
+    /// let prefixes = vec![
+    ///     my_client_key.encrypt_no_padding("GET"),
+    ///     my_client_key.encrypt_no_padding("POST"),
+    /// ];
+    /// let res = my_server_key.starts_with_any(&heistack, &prefixes, &public_parameters);
     /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(dec, 11u8);
+    /// assert_eq!(dec, 1u8);
     /// ```
-    pub fn len(&self, string: &FheString, public_parameters: &PublicParameters) -> FheAsciiChar {
+    pub fn starts_with_any(
+        &self,
+        string: &FheString,
+        prefixes: &[Vec<FheAsciiChar>],
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("starts_with_any");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
-        if string.is_empty() {
-            return zero;
-        }
-
-        let mut result = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        self.prefix_matches(string, prefixes, public_parameters)
+            .iter()
+            .fold(zero, |result, is_match| result.bitor(&self.key, is_match))
+    }
 
-        for i in 0..string.len() {
-            let is_not_zero = string[i].ne(&self.key, &zero);
-            result = result.add(&self.key, &is_not_zero);
-        }
-
-        result
-    }
-
-    /// Repeats a given `FheString` a specified number of times for a max number
-    /// of MAX_REPETITIONS. Max valid repetitions value is 255u8.
+    /// Finds which of several patterns a given `FheString` starts with, the encrypted
+    /// equivalent of a `match` on the prefix of a string.
     ///
-    /// Same as `repeat` but with plaintext repetitions.
+    /// The patterns are checked in the same single pass as `starts_with_any`. When several of
+    /// them match, like `"GET"` and `"GETX"`, the first one in the slice wins.
     ///
-    /// # Example:
+    /// # Arguments
+    /// * `string`: &FheString - The string to check.
+    /// * `prefixes`: &[Vec<FheAsciiChar>] - The unpadded patterns to compare against, by priority.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted index of the first matching pattern, or MAX_FIND_LENGTH
+    /// if none matches.
+    ///
+    /// # Example
     /// ```
-    /// let my_string_plain = "abc";
-    /// let n_plain = 3u8;
+    /// let heistack_plain = "POST /";
     ///
-    /// let my_string = my_client_key.encrypt(
-    ///     my_string_plain,
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
     ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
-    /// let my_string_upper =
-    ///     my_server_key.repeat_clear(&my_string, n_plain.into(), &public_parameters);
-    /// let actual = my_client_key.decrypt(my_string_upper);
+    /// let prefixes = vec![
+    ///     my_client_key.encrypt_no_padding("GET"),
+    ///     my_client_key.encrypt_no_padding("POST"),
+    /// ];
+    /// let res = my_server_key.match_prefix(&heistack, &prefixes, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(actual, "abcabcabc");
+    /// assert_eq!(dec, 1u8);
     /// ```
-    pub fn repeat_clear(
+    pub fn match_prefix(
         &self,
         string: &FheString,
-        repetitions: usize,
+        prefixes: &[Vec<FheAsciiChar>],
         public_parameters: &PublicParameters,
-    ) -> FheString {
-        let mut result = string.clone();
-        let end = repetitions.checked_sub(1);
+    ) -> FheAsciiChar {
+        time_method!("match_prefix");
+        let not_found =
+            FheAsciiChar::encrypt_trivial(MAX_FIND_LENGTH as u8, public_parameters, &self.key);
 
-        match end {
-            Some(end_of_pattern) => {
-                for _ in 0..end_of_pattern {
-                    result.append(string.clone());
+        let branches = self
+            .prefix_matches(string, prefixes, public_parameters)
+            .into_iter()
+            .enumerate()
+            .map(|(k, is_match)| {
+                let enc_k = FheAsciiChar::encrypt_trivial(k as u8, public_parameters, &self.key);
+                (is_match, enc_k)
+            })
+            .collect::<Vec<(FheAsciiChar, FheAsciiChar)>>();
+
+        FheAsciiChar::select(&self.key, &branches, &not_found)
+    }
+
+    // The kth entry is set if the string starts with the kth prefix, computed in a single pass
+    // over the string
+    fn prefix_matches(
+        &self,
+        string: &FheString,
+        prefixes: &[Vec<FheAsciiChar>],
+        public_parameters: &PublicParameters,
+    ) -> Vec<FheAsciiChar> {
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+
+        // A prefix longer than the string can never match, even against padding
+        let mut matches = prefixes
+            .iter()
+            .map(|prefix| {
+                if prefix.len() > string.len() {
+                    zero.clone()
+                } else {
+                    one.clone()
                 }
+            })
+            .collect::<Vec<FheAsciiChar>>();
 
-                utils::bubble_zeroes_right(result, &self.key, public_parameters)
-            }
+        let longest_prefix = prefixes.iter().map(Vec::len).max().unwrap_or(0);
 
-            None => FheString::from_vec(vec![], public_parameters, &self.key),
+        for (i, string_char) in string.iter().take(longest_prefix).enumerate() {
+            for (prefix, is_match) in prefixes.iter().zip(matches.iter_mut()) {
+                if let Some(prefix_char) = prefix.get(i) {
+                    *is_match = is_match.bitand(&self.key, &string_char.eq(&self.key, prefix_char));
+                }
+            }
         }
+
+        matches
     }
 
-    /// Repeats a given `FheString` a specified number of times for a max number
-    /// of MAX_REPETITIONS. Max valid repetitions value is 255u8.
+    /// Checks if a given `FheString` is empty.
     ///
     /// # Arguments
-    /// * `string`: &FheString - The string to be repeated.
-    /// * `repetitions`: FheAsciiChar - Encrypted number of times to repeat the string.
+    /// * `string`: &FheString - The string to check.
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
-    /// `FheString` - The repeated string.
+    /// `FheAsciiChar` - Encrypted 1 if the string is empty, otherwise encrypted 0.
     ///
     /// # Example:
     /// ```
-    /// let my_string_plain = "abc";
-    /// let n_plain = 3u8;
+    /// let my_string_plain = "";
     ///
     /// let my_string = my_client_key.encrypt(
     ///     my_string_plain,
@@ -558,54 +895,48 @@ impl MyServerKey {
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
-    /// let n = my_client_key.encrypt_char(n_plain);
-    /// let my_string_upper = my_server_key.repeat(&my_string, n, &public_parameters);
-    /// let actual = my_client_key.decrypt(my_string_upper);
     ///
-    /// assert_eq!(actual, "abcabcabc");
+    /// let res = my_server_key.is_empty(&my_string, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
     /// ```
-    pub fn repeat(
+    pub fn is_empty(
         &self,
         string: &FheString,
-        repetitions: FheAsciiChar,
         public_parameters: &PublicParameters,
-    ) -> FheString {
+    ) -> FheAsciiChar {
+        time_method!("is_empty");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
-        let mut result = FheString::from_vec(
-            vec![zero.clone(); MAX_REPETITIONS * string.len()],
-            public_parameters,
-            &self.key,
-        );
-        let str_len = string.len();
+        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
 
-        for i in 0..MAX_REPETITIONS {
-            let enc_i = FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
-            let copy_flag = enc_i.lt(&self.key, &repetitions);
+        if string.is_empty() {
+            return one;
+        }
 
-            for j in 0..str_len {
-                result[i * str_len + j] = copy_flag.if_then_else(&self.key, &string[j], &zero);
-            }
+        let mut result = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+
+        for i in 0..string.len() {
+            let eql = string[i].eq(&self.key, &zero);
+            result = result.bitand(&self.key, &eql);
         }
 
-        utils::bubble_zeroes_right(result, &self.key, public_parameters)
+        result
     }
 
-    /// Replaces occurrences of a pattern in a given `FheString` with another pattern.
+    /// Counts the characters of a given `FheString` that are in a set.
     ///
     /// # Arguments
-    /// * `string`: &FheString - The string in which replacements are to be made.
-    /// * `from`: &Vec<FheAsciiChar> - The unpadded pattern to be replaced.
-    /// * `to`: &Vec<FheAsciiChar> - The unpadded pattern to replace with.
+    /// * `string`: &FheString - The string whose characters are counted.
+    /// * `set`: &[FheAsciiChar] - The characters to count, duplicates are counted once.
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
-    /// `FheString` - The string with replacements made.
+    /// `FheAsciiChar` - The encrypted number of characters of the string that are in the set.
     ///
     /// # Example:
     /// ```
-    /// let my_string_plain = "hello world world test";
-    /// let from_plain = "world";
-    /// let to_plain = "abc";
+    /// let my_string_plain = "education";
     ///
     /// let my_string = my_client_key.encrypt(
     ///     my_string_plain,
@@ -613,55 +944,42 @@ impl MyServerKey {
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
-    /// let from = my_client_key.encrypt_no_padding(from_plain);
-    /// let to = my_client_key.encrypt_no_padding(to_plain);
+    /// let vowels = my_client_key.encrypt_no_padding("aeiou");
     ///
-    /// let my_new_string = my_server_key.replace(&my_string, &from, &to, &public_parameters);
-    /// let actual = my_client_key.decrypt(my_new_string);
+    /// let res = my_server_key.count_in_set(&my_string, &vowels, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(actual, "hello abc abc test");
+    /// assert_eq!(dec, 5u8);
     /// ```
-    pub fn replace(
+    pub fn count_in_set(
         &self,
         string: &FheString,
-        from: &Vec<FheAsciiChar>,
-        to: &Vec<FheAsciiChar>,
+        set: &[FheAsciiChar],
         public_parameters: &PublicParameters,
-    ) -> FheString {
-        let n = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
-        if from.len() >= to.len() {
-            Self::handle_longer_from(
-                string.clone(),
-                from.clone(),
-                to.clone(),
-                n,
-                false,
-                &self.key,
-                public_parameters,
-            )
-        } else {
-            Self::handle_shorter_from(
-                string.clone(),
-                from.clone(),
-                to.clone(),
-                n,
-                false,
-                &self.key,
-                public_parameters,
-            )
+    ) -> FheAsciiChar {
+        time_method!("count_in_set");
+        let mut result = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        for string_char in string.iter() {
+            let mut is_in_set = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+            for set_char in set {
+                is_in_set = is_in_set.bitor(&self.key, &string_char.eq(&self.key, set_char));
+            }
+
+            result = result.add(&self.key, &is_in_set);
         }
+
+        result
     }
 
-    /// Replaces occurrences of a plaintext pattern in a given `FheString` with another plaintext
-    /// pattern.
+    /// Counts the characters of a given `FheString` that are in a plaintext set.
     ///
-    /// Same as `replace` but with plaintext patterns.
+    /// Same as `count_in_set` but with a plaintext set.
     ///
     /// # Example:
     /// ```
-    /// let my_string_plain = "hello world world test";
-    /// let from_plain = "world";
-    /// let to_plain = "abc";
+    /// let my_string_plain = "education";
     ///
     /// let my_string = my_client_key.encrypt(
     ///     my_string_plain,
@@ -670,436 +988,2918 @@ impl MyServerKey {
     ///     &my_server_key.key,
     /// );
     ///
-    /// let my_new_string =
-    ///     my_server_key.replace_clear(&my_string, &from_plain, &to_plain, &public_parameters);
-    /// let actual = my_client_key.decrypt(my_new_string);
+    /// let res = my_server_key.count_in_set_clear(&my_string, "aeiou", &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(actual, "hello abc abc test");
+    /// assert_eq!(dec, 5u8);
     /// ```
-    pub fn replace_clear(
+    pub fn count_in_set_clear(
         &self,
         string: &FheString,
-        clear_from: &str,
-        clear_to: &str,
+        clear_set: &str,
         public_parameters: &PublicParameters,
-    ) -> FheString {
-        let from = clear_from
-            .bytes()
-            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
-            .collect::<Vec<FheAsciiChar>>();
-
-        let to = clear_to
+    ) -> FheAsciiChar {
+        time_method!("count_in_set_clear");
+        let set = clear_set
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
             .collect::<Vec<FheAsciiChar>>();
 
-        self.replace(string, &from, &to, public_parameters)
+        self.count_in_set(string, &set, public_parameters)
     }
 
-    /// Finds the last occurrence of a pattern in a given `FheString`.
+    /// Counts the consecutive occurrences of a character at the start of a given `FheString`.
+    ///
+    /// For example the leading zeros of a zero padded number, `count_leading("000042", '0')` is
+    /// 4. The scan keeps a flag that stays set while every character so far is `ch`.
     ///
     /// # Arguments
-    /// * `string`: &FheString - The string to search.
-    /// * `pattern`: &Vec<FheAsciiChar> - The unpadded pattern to find.
+    /// * `string`: &FheString - The string to analyze.
+    /// * `ch`: &FheAsciiChar - The character to count.
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
-    /// `FheAsciiChar` - The encrypted position of the last occurrence of the pattern,
-    /// or encrypted MAX_FIND_LENGTH if not found
+    /// `FheAsciiChar` - The encrypted number of leading occurrences of `ch`.
     ///
     /// # Example:
     /// ```
-    /// let heistack_plain = "hello abc abc test";
-    /// let needle_plain = "abc";
+    /// let my_string_plain = "000042";
     ///
-    /// let heistack = my_client_key.encrypt(
-    ///     heistack_plain,
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
     ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
-    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
-    /// let res = my_server_key.rfind(heistack, &needle, &public_parameters);
+    /// let ch = my_client_key.encrypt_char(b'0');
+    ///
+    /// let res = my_server_key.count_leading(&my_string, &ch, &public_parameters);
     /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(dec, 10u8);
+    /// assert_eq!(dec, 4u8);
     /// ```
-    pub fn rfind(
+    pub fn count_leading(
         &self,
-        mut string: FheString,
-        pattern: &Vec<FheAsciiChar>,
+        string: &FheString,
+        ch: &FheAsciiChar,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
-        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
-        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
-
-        // Quick solution to fix a no padding issue
-        string.push(zero.clone());
-
-        let mut pattern_position =
-            FheAsciiChar::encrypt_trivial(MAX_FIND_LENGTH as u8, public_parameters, &self.key);
-
-        if string.len() >= MAX_FIND_LENGTH + pattern.len() {
-            panic!("Maximum supported size for find reached");
-        }
-
-        // Handle edge case
-        if pattern.is_empty() {
-            let mut last_non_zero_position = zero.clone();
-
-            // Find the last char position that is non \0
-            for i in 0..string.len() {
-                let is_not_zero = string[i].ne(&self.key, &zero);
-                let enc_i =
-                    FheAsciiChar::encrypt_trivial((i + 1) as u8, public_parameters, &self.key);
-                last_non_zero_position =
-                    is_not_zero.if_then_else(&self.key, &enc_i, &last_non_zero_position);
-            }
+        time_method!("count_leading");
+        let mut still_matching = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let mut result = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
-            return last_non_zero_position;
+        for string_char in string.iter() {
+            still_matching = still_matching.bitand(&self.key, &string_char.eq(&self.key, ch));
+            result = result.add(&self.key, &still_matching);
         }
 
-        let end = string.len().checked_sub(pattern.len());
-
-        match end {
-            Some(end_of_pattern) => {
-                // If pattern and string have the same size and are equal
-                // this is needed to actually iterate the loop
-                let end_of_pattern = utils::adjust_end_of_pattern(end_of_pattern);
-
-                // Search for pattern
-                for i in 0..end_of_pattern {
-                    let mut pattern_found_flag = one.clone();
-
-                    // This is okay since pattern.len() <= string.bytes.len()
-                    for (j, pattern_char) in pattern.iter().enumerate() {
-                        pattern_found_flag = pattern_found_flag
-                            .bitand(&self.key, &pattern_char.eq(&self.key, &string[i + j]));
-                    }
-
-                    let enc_i =
-                        FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
-                    pattern_position =
-                        pattern_found_flag.if_then_else(&self.key, &enc_i, &pattern_position);
-                }
-
-                pattern_position
-            }
-            None => FheAsciiChar::encrypt_trivial(255u8, public_parameters, &self.key),
-        }
+        result
     }
 
-    /// Finds the last occurrence of a plaintext pattern in a given `FheString`.
+    /// Counts the consecutive occurrences of a character at the end of a given `FheString`.
     ///
-    /// Same as `rfind` but with a plaintext pattern.
+    /// Same as `count_leading` but from the end, the padding is skipped.
     ///
     /// # Example:
     /// ```
-    /// let heistack_plain = "hello abc abc test";
-    /// let needle_plain = "abc";
+    /// let my_string_plain = "4200";
     ///
-    /// let heistack = my_client_key.encrypt(
-    ///     heistack_plain,
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
     ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
+    /// let ch = my_client_key.encrypt_char(b'0');
     ///
-    /// let res = my_server_key.rfind_clear(&heistack, &needle_plain, &public_parameters);
+    /// let res = my_server_key.count_trailing(&my_string, &ch, &public_parameters);
     /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(dec, 10u8);
+    /// assert_eq!(dec, 2u8);
     /// ```
-    pub fn rfind_clear(
+    pub fn count_trailing(
         &self,
         string: &FheString,
-        clear_pattern: &str,
+        ch: &FheAsciiChar,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
-        let pattern = clear_pattern
-            .bytes()
-            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
-            .collect::<Vec<FheAsciiChar>>();
+        time_method!("count_trailing");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut still_matching = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let mut result = zero.clone();
 
-        self.rfind(string.clone(), &pattern, public_parameters)
+        // The padding is only at the end, so it is all seen before the content
+        for string_char in string.get_bytes().iter().rev() {
+            let is_match = string_char.eq(&self.key, ch);
+            let is_padding = string_char.eq(&self.key, &zero);
+
+            still_matching =
+                still_matching.bitand(&self.key, &is_match.bitor(&self.key, &is_padding));
+            result = result.add(&self.key, &still_matching.bitand(&self.key, &is_match));
+        }
+
+        result
     }
 
-    // The "easy" case
-    fn handle_longer_from(
-        mut bytes: FheString,
-        from: Vec<FheAsciiChar>,
-        mut to: Vec<FheAsciiChar>,
-        n: FheAsciiChar,
-        use_counter: bool,
-        server_key: &tfhe::integer::ServerKey,
-        public_parameters: &PublicParameters,
+    /// Computes the sum of the bytes of a given `FheString` modulo 256, a cheap fingerprint of
+    /// its content.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to fingerprint.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted wrapping sum of the characters.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "zama";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let res = my_server_key.checksum(&my_string, &public_parameters);
+    /// let expected = my_string_plain.bytes().fold(0u8, |a, b| a.wrapping_add(b));
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&res), expected);
+    /// ```
+    pub fn checksum(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("checksum");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        // The padding is \0 so it adds nothing, and the 8 bit radix wraps around by itself
+        string.iter().fold(zero, |result, string_char| {
+            result.add(&self.key, string_char)
+        })
+    }
+
+    /// Counts the occurrences of every byte value in a given `FheString`.
+    ///
+    /// The kth entry of the result is the number of characters equal to `k`, the padding is not
+    /// counted so the entry of \0 is always 0. Every character is compared with every byte
+    /// value, which costs O(256 * n) comparisons for a string of n characters.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to analyze.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `Vec<FheAsciiChar>` - The 256 encrypted counts.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "aaabbc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let histogram = my_server_key.byte_histogram(&my_string, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&histogram[b'a' as usize]), 3u8);
+    /// assert_eq!(my_client_key.decrypt_char(&histogram[b'z' as usize]), 0u8);
+    /// ```
+    pub fn byte_histogram(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> Vec<FheAsciiChar> {
+        time_method!("byte_histogram");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        // The padding is not counted
+        let mut histogram = vec![zero.clone()];
+
+        for value in 1..=u8::MAX {
+            let enc_value = FheAsciiChar::encrypt_trivial(value, public_parameters, &self.key);
+
+            let count = string.iter().fold(zero.clone(), |count, string_char| {
+                count.add(&self.key, &string_char.eq(&self.key, &enc_value))
+            });
+            histogram.push(count);
+        }
+
+        histogram
+    }
+
+    /// Finds the most frequent character of a given `FheString`.
+    ///
+    /// The counts of `byte_histogram` are compared in increasing byte order and a count only
+    /// replaces the current maximum if it is strictly greater, so ties are broken by the lowest
+    /// byte value. An empty string returns \0 with a count of 0.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to analyze.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `(FheAsciiChar, FheAsciiChar)` - The encrypted most frequent character and its count.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "aaabbc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let (most_frequent, count) = my_server_key.most_frequent_char(&my_string, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&most_frequent), b'a');
+    /// assert_eq!(my_client_key.decrypt_char(&count), 3u8);
+    /// ```
+    pub fn most_frequent_char(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> (FheAsciiChar, FheAsciiChar) {
+        time_method!("most_frequent_char");
+        let histogram = self.byte_histogram(string, public_parameters);
+
+        let mut most_frequent = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut max_count = histogram[0].clone();
+
+        for (value, count) in histogram.iter().enumerate().skip(1) {
+            let enc_value =
+                FheAsciiChar::encrypt_trivial(value as u8, public_parameters, &self.key);
+            let is_greater = count.gt(&self.key, &max_count);
+
+            most_frequent = is_greater.if_then_else(&self.key, &enc_value, &most_frequent);
+            max_count = is_greater.if_then_else(&self.key, count, &max_count);
+        }
+
+        (most_frequent, max_count)
+    }
+
+    /// Counts how many distinct characters of a given `FheString` are less frequent than a
+    /// character.
+    ///
+    /// The comparison is strict, so the characters that occur as often as `ch` are not counted
+    /// and characters with the same frequency get the same rank. Only the characters present in
+    /// the string are ranked, if `ch` itself is absent its rank is 0. The counts come from
+    /// `byte_histogram`, so this also costs O(256 * n) comparisons for a string of n characters.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to analyze.
+    /// * `ch`: &FheAsciiChar - The character to rank.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted number of distinct characters occurring fewer times than
+    /// `ch`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "aaabbc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let ch = my_client_key.encrypt_char(b'a');
+    ///
+    /// let res = my_server_key.char_frequency_rank(&my_string, &ch, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&res), 2u8);
+    /// ```
+    pub fn char_frequency_rank(
+        &self,
+        string: &FheString,
+        ch: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("char_frequency_rank");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let histogram = self.byte_histogram(string, public_parameters);
+
+        let ch_count = string.iter().fold(zero.clone(), |count, string_char| {
+            count.add(&self.key, &string_char.eq(&self.key, ch))
+        });
+
+        // The padding entry is always 0 so it is never counted
+        histogram.iter().skip(1).fold(zero.clone(), |rank, count| {
+            let is_present = count.ne(&self.key, &zero);
+            let is_less_frequent = count.lt(&self.key, &ch_count);
+            rank.add(&self.key, &is_present.bitand(&self.key, &is_less_frequent))
+        })
+    }
+
+    /// Run length encodes a given `FheString`.
+    ///
+    /// The kth pair of the result holds the character of the kth run of identical characters and
+    /// the length of that run, so `"aaabb"` gives `[(a, 3), (b, 2), (\0, 0), ...]`. There can
+    /// not be more runs than characters, so the result always has `string.len()` pairs and the
+    /// pairs after the last run are `(\0, 0)`, which hides the number of runs. The counts are
+    /// single encrypted bytes, which is enough since a string can not be longer than 255
+    /// characters. Every position is compared with every run index, which costs O(n^2)
+    /// comparisons for a string of n characters.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to encode.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `Vec<(FheAsciiChar, FheAsciiChar)>` - The encrypted character and length of every run.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "aaabb";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let runs = my_server_key.run_length_encode(&my_string, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&runs[0].0), b'a');
+    /// assert_eq!(my_client_key.decrypt_char(&runs[0].1), 3u8);
+    /// assert_eq!(my_client_key.decrypt_char(&runs[1].0), b'b');
+    /// assert_eq!(my_client_key.decrypt_char(&runs[1].1), 2u8);
+    /// assert_eq!(my_client_key.decrypt_char(&runs[2].1), 0u8);
+    /// ```
+    pub fn run_length_encode(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> Vec<(FheAsciiChar, FheAsciiChar)> {
+        time_method!("run_length_encode");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        let is_content: Vec<FheAsciiChar> = string
+            .iter()
+            .map(|string_char| string_char.ne(&self.key, &zero))
+            .collect();
+
+        // 1-based number of the run of every character, a run starts at every character that
+        // differs from the previous one. The padding gets the number of the last run
+        let mut run_numbers = Vec::with_capacity(string.len());
+        let mut run_number = zero.clone();
+        for i in 0..string.len() {
+            let starts_run = if i == 0 {
+                is_content[0].clone()
+            } else {
+                string[i]
+                    .ne(&self.key, &string[i - 1])
+                    .bitand(&self.key, &is_content[i])
+            };
+            run_number = run_number.add(&self.key, &starts_run);
+            run_numbers.push(run_number.clone());
+        }
+
+        (1..=string.len())
+            .map(|run| {
+                let enc_run =
+                    FheAsciiChar::encrypt_trivial(run as u8, public_parameters, &self.key);
+
+                let in_run: Vec<FheAsciiChar> = run_numbers
+                    .iter()
+                    .zip(is_content.iter())
+                    .map(|(run_number, is_content)| {
+                        run_number
+                            .eq(&self.key, &enc_run)
+                            .bitand(&self.key, is_content)
+                    })
+                    .collect();
+
+                let branches: Vec<(FheAsciiChar, FheAsciiChar)> =
+                    in_run.iter().cloned().zip(string.iter().cloned()).collect();
+                let run_char = FheAsciiChar::select(&self.key, &branches, &zero);
+
+                let run_length = in_run
+                    .iter()
+                    .fold(zero.clone(), |count, in_run| count.add(&self.key, in_run));
+
+                (run_char, run_length)
+            })
+            .collect()
+    }
+
+    /// Maps every character of a given `FheString` through a plaintext lookup table.
+    ///
+    /// Each character `c` is replaced with `table[c]`, which can express any per character
+    /// transform such as case conversion or rot13. tfhe does not expose a lookup table over a
+    /// whole radix ciphertext, so the table is applied with one equality per entry that changes
+    /// its input, the identity entries cost nothing. The entry of \0 is ignored so that the
+    /// padding is preserved.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to transform.
+    /// * `table`: &[u8; 256] - The image of every byte value.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The transformed string.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "Hello";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let mut rot13 = [0u8; 256];
+    /// for (value, entry) in rot13.iter_mut().enumerate() {
+    ///     *entry = match value as u8 {
+    ///         c @ b'a'..=b'z' => (c - b'a' + 13) % 26 + b'a',
+    ///         c @ b'A'..=b'Z' => (c - b'A' + 13) % 26 + b'A',
+    ///         c => c,
+    ///     };
+    /// }
+    ///
+    /// let my_new_string = my_server_key.apply_lut(&my_string, &rot13, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_new_string);
+    ///
+    /// assert_eq!(actual, "Uryyb");
+    /// ```
+    pub fn apply_lut(
+        &self,
+        string: &FheString,
+        table: &[u8; 256],
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("apply_lut");
+        // Only the entries that change their input need a comparison
+        let entries = (1..=u8::MAX)
+            .filter(|value| table[*value as usize] != *value)
+            .map(|value| {
+                (
+                    FheAsciiChar::encrypt_trivial(value, public_parameters, &self.key),
+                    FheAsciiChar::encrypt_trivial(
+                        table[value as usize],
+                        public_parameters,
+                        &self.key,
+                    ),
+                )
+            })
+            .collect::<Vec<(FheAsciiChar, FheAsciiChar)>>();
+
+        let bytes = string
+            .iter()
+            .map(|string_char| {
+                let branches = entries
+                    .iter()
+                    .map(|(input, output)| (string_char.eq(&self.key, input), output.clone()))
+                    .collect::<Vec<(FheAsciiChar, FheAsciiChar)>>();
+
+                FheAsciiChar::select(&self.key, &branches, string_char)
+            })
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Replaces every character of a given `FheString` that is in a set by its counterpart in
+    /// another set, like the Unix `tr` command.
+    ///
+    /// Each character equal to `from[k]` is replaced with `to[k]`, so the length of the string
+    /// is preserved. If a character appears several times in `from`, the last occurrence is used.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to translate.
+    /// * `from`: &[FheAsciiChar] - The characters to replace.
+    /// * `to`: &[FheAsciiChar] - The replacement of each character of `from`, must have the same
+    /// length as `from`.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The translated string.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let from = my_client_key.encrypt_no_padding("lo");
+    /// let to = my_client_key.encrypt_no_padding("L0");
+    ///
+    /// let translated = my_server_key.translate(&my_string, &from, &to, &public_parameters);
+    /// let actual = my_client_key.decrypt(translated);
+    ///
+    /// assert_eq!(actual, "heLL0");
+    /// ```
+    pub fn translate(
+        &self,
+        string: &FheString,
+        from: &[FheAsciiChar],
+        to: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("translate");
+        assert_eq!(
+            from.len(),
+            to.len(),
+            "Every character to translate must have a replacement"
+        );
+
+        let bytes = string
+            .iter()
+            .map(|string_char| {
+                // The last occurrence in from has the highest priority, the original character is
+                // compared so that a replacement is never translated again
+                let branches = from
+                    .iter()
+                    .zip(to)
+                    .rev()
+                    .map(|(from_char, to_char)| {
+                        (string_char.eq(&self.key, from_char), to_char.clone())
+                    })
+                    .collect::<Vec<(FheAsciiChar, FheAsciiChar)>>();
+
+                FheAsciiChar::select(&self.key, &branches, string_char)
+            })
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Replaces every character of a given `FheString` that is in a plaintext set by its
+    /// counterpart in another plaintext set.
+    ///
+    /// Same as `translate` but with plaintext sets.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let translated = my_server_key.translate_clear(&my_string, "lo", "L0", &public_parameters);
+    /// let actual = my_client_key.decrypt(translated);
+    ///
+    /// assert_eq!(actual, "heLL0");
+    /// ```
+    pub fn translate_clear(
+        &self,
+        string: &FheString,
+        clear_from: &str,
+        clear_to: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("translate_clear");
+        let from = clear_from
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        let to = clear_to
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        self.translate(string, &from, &to, public_parameters)
+    }
+
+    /// Converts a `snake_case` `FheString` to `Title Case`.
+    ///
+    /// The underscores are replaced with spaces and the first character of every word is
+    /// converted to uppercase, the other characters are kept as they are.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The snake case string to convert.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The words of the string separated by spaces and capitalized.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello_world";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.title_from_snake_case(&my_string, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "Hello World");
+    /// ```
+    pub fn title_from_snake_case(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("title_from_snake_case");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let spaced = self.translate_clear(string, "_", " ", public_parameters);
+
+        // A word starts at the beginning of the string or after a space
+        let mut previous_is_space =
+            FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let bytes = spaced
+            .iter()
+            .map(|c| {
+                let to_upper = previous_is_space.bitand(&self.key, &c.is_lowercase(&self.key));
+                previous_is_space = c.is_whitespace(&self.key);
+                c.sub(
+                    &self.key,
+                    &to_upper.if_then_else(&self.key, &spaced.get_cst(), &zero),
+                )
+            })
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::new(bytes, spaced.get_cst())
+    }
+
+    /// Keeps only the characters of a given `FheString` that match a predicate, like
+    /// `String::retain`.
+    ///
+    /// The other characters are replaced with \0 and moved to the end, so the result is a
+    /// compacted string with the same encrypted length as the input.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to filter.
+    /// * `keep`: impl Fn(&FheAsciiChar) -> FheAsciiChar - Returns encrypted 1 for the characters
+    /// to keep, and encrypted 0 otherwise.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string without the characters that don't match the predicate.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a1b2c3";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let digits = my_server_key.retain(
+    ///     &my_string,
+    ///     |c| c.is_ascii_digit(&my_server_key.key),
+    ///     &public_parameters,
+    /// );
+    /// let actual = my_client_key.decrypt(digits);
+    ///
+    /// assert_eq!(actual, "123");
+    /// ```
+    pub fn retain(
+        &self,
+        string: &FheString,
+        keep: impl Fn(&FheAsciiChar) -> FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("retain");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut result = string.clone();
+
+        for result_char in result.iter_mut() {
+            *result_char = keep(result_char).if_then_else(&self.key, result_char, &zero);
+        }
+
+        utils::bubble_zeroes_right(result, &self.key, public_parameters)
+    }
+
+    /// Removes the characters of a given `FheString` that match a predicate.
+    ///
+    /// Same as `retain` with the opposite predicate, for example to remove all whitespace.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a b\tc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let no_whitespace = my_server_key.remove_matching(
+    ///     &my_string,
+    ///     |c| c.is_whitespace(&my_server_key.key),
+    ///     &public_parameters,
+    /// );
+    /// let actual = my_client_key.decrypt(no_whitespace);
+    ///
+    /// assert_eq!(actual, "abc");
+    /// ```
+    pub fn remove_matching(
+        &self,
+        string: &FheString,
+        remove: impl Fn(&FheAsciiChar) -> FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("remove_matching");
+        self.retain(
+            string,
+            |c| remove(c).flip(&self.key, public_parameters),
+            public_parameters,
+        )
+    }
+
+    /// Removes the characters of a given `FheString` at the positions hidden by a plaintext mask.
+    ///
+    /// The character at position `i` is kept if `keep[i]` is true, the positions past the end of
+    /// the mask are kept. Since the mask is known, the hidden characters are dropped without any
+    /// encrypted comparison, only the shift of the kept characters is done obliviously.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to mask.
+    /// * `keep`: &[bool] - Whether to keep the character at every position.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The kept characters.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.mask(&my_string, &[true, false, true], &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "ac");
+    /// ```
+    pub fn mask(
+        &self,
+        string: &FheString,
+        keep: &[bool],
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("mask");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut result = string.clone();
+
+        for (result_char, _) in result.iter_mut().zip(keep).filter(|(_, &keep)| !keep) {
+            *result_char = zero.clone();
+        }
+
+        utils::bubble_zeroes_right(result, &self.key, public_parameters)
+    }
+
+    /// Replaces the characters of a given `FheString` at the positions hidden by a plaintext mask
+    /// with a fill character.
+    ///
+    /// Same as `mask` but the length is preserved, for example to display a redacted field. The
+    /// padding is never replaced, so a mask longer than the content does not extend it.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to mask.
+    /// * `keep`: &[bool] - Whether to keep the character at every position.
+    /// * `fill`: &FheAsciiChar - The character replacing the hidden ones.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string with the hidden characters replaced.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let fill = my_client_key.encrypt_char(b'*');
+    ///
+    /// let keep = [true, false, true];
+    /// let res = my_server_key.mask_fill(&my_string, &keep, &fill, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "a*c");
+    /// ```
+    pub fn mask_fill(
+        &self,
+        string: &FheString,
+        keep: &[bool],
+        fill: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("mask_fill");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut result = string.clone();
+
+        for (result_char, _) in result.iter_mut().zip(keep).filter(|(_, &keep)| !keep) {
+            let is_padding = result_char.eq(&self.key, &zero);
+            *result_char = is_padding.if_then_else(&self.key, &zero, fill);
+        }
+
+        result
+    }
+
+    /// Replaces every ASCII digit of a given `FheString` with a mask character.
+    ///
+    /// The other characters and the length are preserved, for example to hide a card number while
+    /// keeping its formatting.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to redact.
+    /// * `mask`: &FheAsciiChar - The character replacing the digits.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string with its digits replaced.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "id-4521";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let mask = my_client_key.encrypt_char(b'*');
+    ///
+    /// let res = my_server_key.redact_digits(&my_string, &mask, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "id-****");
+    /// ```
+    pub fn redact_digits(
+        &self,
+        string: &FheString,
+        mask: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("redact_digits");
+        let bytes = string
+            .iter()
+            .map(|c| c.is_ascii_digit(&self.key).if_then_else(&self.key, mask, c))
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Replaces all but the last `n` characters of a given `FheString` with a mask character.
+    ///
+    /// Same masking as `****5678`, the padding is not replaced and nothing is masked if `n` is at
+    /// least the length of the string. Combined with `redact_digits` this covers the usual
+    /// masking of financial data.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to mask.
+    /// * `n`: usize - The number of characters left visible at the end.
+    /// * `mask`: &FheAsciiChar - The character replacing the other ones.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The masked string, of the same length as the input.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "12345678";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let mask = my_client_key.encrypt_char(b'*');
+    ///
+    /// let res = my_server_key.keep_last_n(&my_string, 4, &mask, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "****5678");
+    /// ```
+    pub fn keep_last_n(
+        &self,
+        string: &FheString,
+        n: usize,
+        mask: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("keep_last_n");
+        let len = self.len(string, public_parameters);
+
+        // Position i is masked if i < len - n, written as i + n < len so that it can not
+        // underflow or overflow. The length fits in a byte, so past 255 nothing is masked
+        let bytes = string
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let visible_from = i.checked_add(n).and_then(|v| u8::try_from(v).ok());
+                match visible_from {
+                    Some(visible_from) => {
+                        let enc_visible_from = FheAsciiChar::encrypt_trivial(
+                            visible_from,
+                            public_parameters,
+                            &self.key,
+                        );
+                        enc_visible_from
+                            .lt(&self.key, &len)
+                            .if_then_else(&self.key, mask, c)
+                    }
+                    None => c.clone(),
+                }
+            })
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::new(bytes, string.get_cst())
+    }
+
+    /// Removes consecutive repeated characters of a given `FheString`.
+    ///
+    /// Same as `Vec::dedup` on the characters, every run of identical characters is collapsed
+    /// into a single one.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to deduplicate.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string without adjacent duplicate characters.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "aaabccd";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.dedup(&my_string, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "abcd");
+    /// ```
+    pub fn dedup(&self, string: &FheString, public_parameters: &PublicParameters) -> FheString {
+        time_method!("dedup");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut result = string.clone();
+
+        // Compare against the input so that the whole run is removed, not every other character
+        for i in 1..string.len() {
+            let is_repeated = string[i].eq(&self.key, &string[i - 1]);
+            result[i] = is_repeated.if_then_else(&self.key, &zero, &string[i]);
+        }
+
+        utils::bubble_zeroes_right(result, &self.key, public_parameters)
+    }
+
+    /// Checks if a given `FheString` is a non empty sequence of ASCII digits.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to check.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - Encrypted 1 if the string is not empty and all its characters are ASCII
+    /// digits, otherwise encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "12345";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.is_numeric_string(&my_string, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
+    /// ```
+    pub fn is_numeric_string(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("is_numeric_string");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut all_digits = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+
+        // The padding is ignored, but there has to be at least one digit
+        for string_char in string.iter() {
+            let is_padding = string_char.eq(&self.key, &zero);
+            let is_digit = string_char.is_ascii_digit(&self.key);
+            all_digits = all_digits.bitand(&self.key, &is_digit.bitor(&self.key, &is_padding));
+        }
+
+        let is_empty = self.is_empty(string, public_parameters);
+        all_digits.bitand(&self.key, &is_empty.flip(&self.key, public_parameters))
+    }
+
+    /// Checks if a given `FheString` is a non empty sequence of ASCII alphanumeric characters.
+    ///
+    /// Same as `is_numeric_string` but letters are also accepted, for example to validate a
+    /// username.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to check.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - Encrypted 1 if the string is not empty and all its characters are ASCII
+    /// letters or digits, otherwise encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "user123";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.is_alphanumeric_string(&my_string, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
+    /// ```
+    pub fn is_alphanumeric_string(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("is_alphanumeric_string");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut all_alphanumeric = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+
+        // The padding is ignored, but there has to be at least one character
+        for string_char in string.iter() {
+            let is_padding = string_char.eq(&self.key, &zero);
+            let is_alphanumeric = string_char.is_ascii_alphanumeric(&self.key);
+            all_alphanumeric =
+                all_alphanumeric.bitand(&self.key, &is_alphanumeric.bitor(&self.key, &is_padding));
+        }
+
+        let is_empty = self.is_empty(string, public_parameters);
+        all_alphanumeric.bitand(&self.key, &is_empty.flip(&self.key, public_parameters))
+    }
+
+    /// Checks if a given `FheString` has the shape of an email address.
+    ///
+    /// This is a sanity check, not a validation against the RFC. The rules are:
+    /// * there is exactly one `@`,
+    /// * the part before it is not empty,
+    /// * the part after it contains at least one `.`, so it is not empty either.
+    ///
+    /// The characters themselves are not restricted, so `"a b@c.d"` passes. All the rules are
+    /// checked in a single pass over the string.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to check.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - Encrypted 1 if the string follows the rules, otherwise encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "alice@example.com";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.looks_like_email(&my_string, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
+    /// ```
+    pub fn looks_like_email(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("looks_like_email");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let at = FheAsciiChar::encrypt_trivial(b'@', public_parameters, &self.key);
+        let dot = FheAsciiChar::encrypt_trivial(b'.', public_parameters, &self.key);
+
+        let Some(first_char) = string.iter().next() else {
+            return zero;
+        };
+
+        // The local part is not empty if the string does not start with the @ or the padding
+        let local_not_empty = first_char
+            .ne(&self.key, &at)
+            .bitand(&self.key, &first_char.ne(&self.key, &zero));
+
+        let mut at_count = zero.clone();
+        let mut at_found = zero.clone();
+        let mut dot_in_domain = zero.clone();
+
+        for string_char in string.iter() {
+            let is_at = string_char.eq(&self.key, &at);
+            at_count = at_count.add(&self.key, &is_at);
+            at_found = at_found.bitor(&self.key, &is_at);
+
+            let is_dot = string_char.eq(&self.key, &dot);
+            dot_in_domain = dot_in_domain.bitor(&self.key, &is_dot.bitand(&self.key, &at_found));
+        }
+
+        at_count
+            .eq(&self.key, &one)
+            .bitand(&self.key, &local_not_empty)
+            .bitand(&self.key, &dot_in_domain)
+    }
+
+    /// Checks if all characters of a given `FheString` are within the ASCII range.
+    ///
+    /// The encrypted strings are always ASCII, but custom arithmetic on their characters can
+    /// produce values of 0x80 and above, this allows to validate such outputs.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to check.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - Encrypted 1 if all characters are ASCII, otherwise encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello world";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.is_ascii(&my_string, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
+    /// ```
+    pub fn is_ascii(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("is_ascii");
+        let mut result = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+
+        for string_char in string.iter() {
+            result = result.bitand(&self.key, &string_char.is_ascii(&self.key));
+        }
+
+        result
+    }
+
+    /// Checks if the characters of a given `FheString` are in non-decreasing order.
+    ///
+    /// Same as `s.chars().is_sorted()`, the padding is ignored and the empty string is sorted.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to check.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - Encrypted 1 if every character is less than or equal to the next one,
+    /// otherwise encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.is_sorted(&my_string, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
+    /// ```
+    pub fn is_sorted(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("is_sorted");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut result = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+
+        // A pair whose second character is padding is always in order, the padding only comes
+        // after the content so that covers the end of the string
+        for i in 1..string.len() {
+            let in_order = string[i - 1].le(&self.key, &string[i]);
+            let is_padding = string[i].eq(&self.key, &zero);
+            result = result.bitand(&self.key, &in_order.bitor(&self.key, &is_padding));
+        }
+
+        result
+    }
+
+    /// Computes the length of a given `FheString`.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string whose length is to be computed.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted length of the string, without the padding
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello world";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.len(&my_string, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 11u8);
+    /// ```
+    pub fn len(&self, string: &FheString, public_parameters: &PublicParameters) -> FheAsciiChar {
+        time_method!("len");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        if string.is_empty() {
+            return zero;
+        }
+
+        let mut result = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        for i in 0..string.len() {
+            let is_not_zero = string[i].ne(&self.key, &zero);
+            result = result.add(&self.key, &is_not_zero);
+        }
+
+        result
+    }
+
+    /// Returns the character of a given `FheString` at an encrypted position.
+    ///
+    /// Every position is compared against the index and the matching character is selected, so
+    /// the access pattern is the same whatever the index and reveals nothing about it.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to index.
+    /// * `index`: &FheAsciiChar - The encrypted position of the character.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted character at `index`, or encrypted 0 if the index is out
+    /// of bounds or in the padding.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello world";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let index = my_client_key.encrypt_char(6u8);
+    ///
+    /// let res = my_server_key.char_at(&my_string, &index, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, b'w');
+    /// ```
+    pub fn char_at(
+        &self,
+        string: &FheString,
+        index: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("char_at");
+        let mut result = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        // An encrypted index can't reach past 255
+        for (i, string_char) in string.iter().enumerate().take(u8::MAX as usize + 1) {
+            let is_index = index.scalar_eq(&self.key, i as u8);
+            result = is_index.if_then_else(&self.key, string_char, &result);
+        }
+
+        result
+    }
+
+    /// Returns the characters of a given `FheString` in the plaintext range `[start, end)`.
+    ///
+    /// The characters are cloned into a new `FheString` with the same `cst`, nothing is
+    /// computed on the ciphertexts. `end` is clamped to the capacity of the string and an empty
+    /// string is returned if `start >= end`. The range is over the capacity, so a range that
+    /// goes past the visible length ends with padding, for example to extract the fixed width
+    /// fields of an encrypted record.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to slice.
+    /// * `start`: usize - The position of the first character, inclusive.
+    /// * `end`: usize - The position after the last character, exclusive.
+    ///
+    /// # Returns
+    /// `FheString` - The characters of the range.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello world";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.substring(&my_string, 6, 11);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "world");
+    /// ```
+    pub fn substring(&self, string: &FheString, start: usize, end: usize) -> FheString {
+        time_method!("substring");
+        let end = usize::min(end, string.len());
+
+        let bytes = string
+            .iter()
+            .take(end)
+            .skip(start)
+            .cloned()
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::new(bytes, string.get_cst())
+    }
+
+    /// Returns the characters of a given `FheString` in an encrypted range, starting at `start`
+    /// and `len` characters long.
+    ///
+    /// Same as `substring` but the range stays secret: the result always has the capacity of
+    /// `string`, with the characters of the range followed by padding, so its size does not
+    /// reveal the length of the range. The kth character of the result is read with `char_at`
+    /// at `start` in `string` without its first k characters, and kept if `k < len`. This is
+    /// O(n^2) in the capacity of the string. The characters of the range past the end of the
+    /// string are \0.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to slice.
+    /// * `start`: &FheAsciiChar - The encrypted position of the first character.
+    /// * `len`: &FheAsciiChar - The encrypted number of characters.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The characters of the range, padded to the capacity of `string`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello world";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let start = my_client_key.encrypt_char(6u8);
+    /// let len = my_client_key.encrypt_char(5u8);
+    ///
+    /// let res = my_server_key.substring_enc(&my_string, &start, &len, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "world");
+    /// ```
+    pub fn substring_enc(
+        &self,
+        string: &FheString,
+        start: &FheAsciiChar,
+        len: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("substring_enc");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        let bytes = (0..string.len())
+            .map(|k| {
+                // An encrypted length can't reach past 255
+                let Ok(clear_k) = u8::try_from(k) else {
+                    return zero.clone();
+                };
+
+                // The character at start + k, without an encrypted addition that could overflow
+                let source = self.char_at(
+                    &self.substring(string, k, string.len()),
+                    start,
+                    public_parameters,
+                );
+                len.scalar_gt(&self.key, clear_k)
+                    .if_then_else(&self.key, &source, &zero)
+            })
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Repeats a given `FheString` a specified number of times for a max number
+    /// of MAX_REPETITIONS. Max valid repetitions value is 255u8.
+    ///
+    /// Same as `repeat` but with plaintext repetitions. Like `repeat`, a value above
+    /// `MyServerKey::MAX_REPETITIONS` is truncated to it instead of growing the output
+    /// without bound.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abc";
+    /// let n_plain = 3u8;
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let my_string_upper =
+    ///     my_server_key.repeat_clear(&my_string, n_plain.into(), &public_parameters);
+    /// let actual = my_client_key.decrypt(my_string_upper);
+    ///
+    /// assert_eq!(actual, "abcabcabc");
+    /// ```
+    pub fn repeat_clear(
+        &self,
+        string: &FheString,
+        repetitions: usize,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("repeat_clear");
+        let repetitions = usize::min(repetitions, Self::MAX_REPETITIONS);
+        let mut result = string.clone();
+        let end = repetitions.checked_sub(1);
+
+        match end {
+            Some(end_of_pattern) => {
+                for _ in 0..end_of_pattern {
+                    result.append(string.clone());
+                }
+
+                utils::bubble_zeroes_right(result, &self.key, public_parameters)
+            }
+
+            None => FheString::from_vec(vec![], public_parameters, &self.key),
+        }
+    }
+
+    /// Repeats a given `FheString` a specified number of times for a max number
+    /// of MAX_REPETITIONS. Max valid repetitions value is 255u8.
+    ///
+    /// Since `repetitions` is encrypted it can't be validated, a value above MAX_REPETITIONS
+    /// silently produces only MAX_REPETITIONS copies. Use `repeat_checked` to detect this.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to be repeated.
+    /// * `repetitions`: FheAsciiChar - Encrypted number of times to repeat the string.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The repeated string.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abc";
+    /// let n_plain = 3u8;
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let n = my_client_key.encrypt_char(n_plain);
+    /// let my_string_upper = my_server_key.repeat(&my_string, n, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_string_upper);
+    ///
+    /// assert_eq!(actual, "abcabcabc");
+    /// ```
+    pub fn repeat(
+        &self,
+        string: &FheString,
+        repetitions: FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("repeat");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let str_len = string.len_upper_bound();
+        let mut result = FheString::from_vec(
+            vec![zero.clone(); Self::MAX_REPETITIONS * str_len],
+            public_parameters,
+            &self.key,
+        );
+
+        for i in 0..Self::MAX_REPETITIONS {
+            let enc_i = FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
+            let copy_flag = enc_i.lt(&self.key, &repetitions);
+
+            for j in 0..str_len {
+                result[i * str_len + j] = copy_flag.if_then_else(&self.key, &string[j], &zero);
+            }
+        }
+
+        utils::bubble_zeroes_right(result, &self.key, public_parameters)
+    }
+
+    /// Repeats a given `FheString` a specified number of times for a max number
+    /// of MAX_REPETITIONS, and flags whether the result was truncated.
+    ///
+    /// Same as `repeat`, but also returns an encrypted overflow flag which is computed
+    /// obliviously by comparing `repetitions` to MAX_REPETITIONS.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to be repeated.
+    /// * `repetitions`: FheAsciiChar - Encrypted number of times to repeat the string.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `(FheString, FheAsciiChar)` - The repeated string and encrypted 1 if `repetitions` was
+    /// larger than MAX_REPETITIONS, meaning that the string holds only MAX_REPETITIONS copies,
+    /// otherwise encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abc";
+    /// let n_plain = 17u8;
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let n = my_client_key.encrypt_char(n_plain);
+    /// let (my_string_repeated, overflow) =
+    ///     my_server_key.repeat_checked(&my_string, n, &public_parameters);
+    ///
+    /// assert_eq!(
+    ///     my_client_key.decrypt(my_string_repeated),
+    ///     my_string_plain.repeat(MAX_REPETITIONS)
+    /// );
+    /// assert_eq!(my_client_key.decrypt_char(&overflow), 1u8);
+    /// ```
+    pub fn repeat_checked(
+        &self,
+        string: &FheString,
+        repetitions: FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> (FheString, FheAsciiChar) {
+        time_method!("repeat_checked");
+        let overflow = repetitions.scalar_gt(&self.key, Self::MAX_REPETITIONS as u8);
+
+        (
+            self.repeat(string, repetitions, public_parameters),
+            overflow,
+        )
+    }
+
+    /// Replaces occurrences of a pattern in a given `FheString` with another pattern.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string in which replacements are to be made.
+    /// * `from`: &Vec<FheAsciiChar> - The unpadded pattern to be replaced.
+    /// * `to`: &Vec<FheAsciiChar> - The unpadded pattern to replace with.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string with replacements made.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello world world test";
+    /// let from_plain = "world";
+    /// let to_plain = "abc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let from = my_client_key.encrypt_no_padding(from_plain);
+    /// let to = my_client_key.encrypt_no_padding(to_plain);
+    ///
+    /// let my_new_string = my_server_key.replace(&my_string, &from, &to, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_new_string);
+    ///
+    /// assert_eq!(actual, "hello abc abc test");
+    /// ```
+    pub fn replace(
+        &self,
+        string: &FheString,
+        from: &Vec<FheAsciiChar>,
+        to: &Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("replace");
+        if from.len() >= to.len() {
+            Self::handle_longer_from(
+                string.clone(),
+                from.clone(),
+                to.clone(),
+                ReplaceCount::All,
+                &self.key,
+                public_parameters,
+            )
+        } else {
+            Self::handle_shorter_from(
+                string.clone(),
+                from.clone(),
+                to.clone(),
+                ReplaceCount::All,
+                &self.key,
+                public_parameters,
+            )
+        }
+    }
+
+    /// Replaces occurrences of a plaintext pattern in a given `FheString` with another plaintext
+    /// pattern.
+    ///
+    /// Same as `replace` but with plaintext patterns.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "hello world world test";
+    /// let from_plain = "world";
+    /// let to_plain = "abc";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let my_new_string =
+    ///     my_server_key.replace_clear(&my_string, &from_plain, &to_plain, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_new_string);
+    ///
+    /// assert_eq!(actual, "hello abc abc test");
+    /// ```
+    pub fn replace_clear(
+        &self,
+        string: &FheString,
+        clear_from: &str,
+        clear_to: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("replace_clear");
+        let from = clear_from
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        let to = clear_to
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        self.replace(string, &from, &to, public_parameters)
+    }
+
+    /// Replaces the first occurrence of a pattern in a given `FheString` with another pattern.
+    ///
+    /// Same result as `replacen` with `n = 1`, but a single "already replaced" flag is used
+    /// instead of an encrypted counter.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string in which the replacement is to be made.
+    /// * `from`: &Vec<FheAsciiChar> - The unpadded pattern to be replaced.
+    /// * `to`: &Vec<FheAsciiChar> - The unpadded pattern to replace with.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string with the first occurrence of the pattern replaced.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a-a-a";
+    /// let from_plain = "a";
+    /// let to_plain = "X";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let from = my_client_key.encrypt_no_padding(from_plain);
+    /// let to = my_client_key.encrypt_no_padding(to_plain);
+    ///
+    /// let my_new_string = my_server_key.replace_first(&my_string, &from, &to, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_new_string);
+    ///
+    /// assert_eq!(actual, "X-a-a");
+    /// ```
+    pub fn replace_first(
+        &self,
+        string: &FheString,
+        from: &Vec<FheAsciiChar>,
+        to: &Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("replace_first");
+        if from.len() >= to.len() {
+            Self::handle_longer_from(
+                string.clone(),
+                from.clone(),
+                to.clone(),
+                ReplaceCount::First,
+                &self.key,
+                public_parameters,
+            )
+        } else {
+            Self::handle_shorter_from(
+                string.clone(),
+                from.clone(),
+                to.clone(),
+                ReplaceCount::First,
+                &self.key,
+                public_parameters,
+            )
+        }
+    }
+
+    /// Replaces the first occurrence of a plaintext pattern in a given `FheString` with another
+    /// plaintext pattern.
+    ///
+    /// Same as `replace_first` but with plaintext patterns.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a-a-a";
+    /// let from_plain = "a";
+    /// let to_plain = "X";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let my_new_string =
+    ///     my_server_key.replace_first_clear(&my_string, &from_plain, &to_plain, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_new_string);
+    ///
+    /// assert_eq!(actual, "X-a-a");
+    /// ```
+    pub fn replace_first_clear(
+        &self,
+        string: &FheString,
+        clear_from: &str,
+        clear_to: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("replace_first_clear");
+        let from = clear_from
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        let to = clear_to
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        self.replace_first(string, &from, &to, public_parameters)
+    }
+
+    /// Replaces the characters in a plaintext range of a given `FheString` with an encrypted
+    /// fragment, like `String::replace_range`.
+    ///
+    /// Since the range is known, the prefix and suffix are copied as is. The length of the
+    /// content is encrypted, so the range may reach past it into the padding, in which case the
+    /// replacement is appended to the content. The result is always bubbled to move the padding
+    /// left between the content and the replacement to the end.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string in which the range will be replaced.
+    /// * `range`: Range<usize> - The plaintext range to be replaced.
+    /// * `replacement`: &Vec<FheAsciiChar> - The unpadded fragment to put in place of the range.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string with the range replaced.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "name:JOHN;age:42";
+    /// let replacement_plain = "ALEXANDER";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let replacement = my_client_key.encrypt_no_padding(replacement_plain);
+    ///
+    /// let my_new_string =
+    ///     my_server_key.replace_range(&my_string, 5..9, &replacement, &public_parameters);
+    /// let actual = my_client_key.decrypt(my_new_string);
+    ///
+    /// assert_eq!(actual, "name:ALEXANDER;age:42");
+    /// ```
+    pub fn replace_range(
+        &self,
+        string: &FheString,
+        range: Range<usize>,
+        replacement: &Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("replace_range");
+        assert!(
+            range.start <= range.end && range.end <= string.len_upper_bound(),
+            "Range is out of bounds for the given string"
+        );
+
+        // Same size, we can overwrite the characters in place
+        if replacement.len() == range.len() {
+            let mut result = string.clone();
+            for (i, replacement_char) in range.zip(replacement) {
+                result[i] = replacement_char.clone();
+            }
+            // The range may start after a \0 of the padding
+            return utils::bubble_zeroes_right(result, &self.key, public_parameters);
+        }
+
+        let mut result =
+            FheString::from_vec(string[..range.start].to_vec(), public_parameters, &self.key);
+
+        for replacement_char in replacement {
+            result.push(replacement_char.clone());
+        }
+
+        for string_char in string.iter().skip(range.end) {
+            result.push(string_char.clone());
+        }
+
+        utils::bubble_zeroes_right(result, &self.key, public_parameters)
+    }
+
+    /// Finds the last occurrence of a pattern in a given `FheString`.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search.
+    /// * `pattern`: &Vec<FheAsciiChar> - The unpadded pattern to find.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted position of the last occurrence of the pattern,
+    /// or encrypted MAX_FIND_LENGTH if not found
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "hello abc abc test";
+    /// let needle_plain = "abc";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
+    /// let res = my_server_key.rfind(heistack, &needle, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 10u8);
+    /// ```
+    pub fn rfind(
+        &self,
+        string: FheString,
+        pattern: &Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("rfind");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        let string = string.terminated(public_parameters, &self.key);
+
+        let not_found =
+            FheAsciiChar::encrypt_trivial(MAX_FIND_LENGTH as u8, public_parameters, &self.key);
+
+        if string.len() >= MAX_FIND_LENGTH + pattern.len() {
+            panic!("Maximum supported size for find reached");
+        }
+
+        // Handle edge case
+        if pattern.is_empty() {
+            let mut last_non_zero_position = zero.clone();
+
+            // Find the last char position that is non \0
+            for i in 0..string.len() {
+                let is_not_zero = string[i].ne(&self.key, &zero);
+                let enc_i =
+                    FheAsciiChar::encrypt_trivial((i + 1) as u8, public_parameters, &self.key);
+                last_non_zero_position =
+                    is_not_zero.if_then_else(&self.key, &enc_i, &last_non_zero_position);
+            }
+
+            return last_non_zero_position;
+        }
+
+        // A later match overrides the earlier ones, the offset that reaches the terminator never
+        // matches
+        self.pattern_match_flags(&string, pattern, public_parameters)
+            .iter()
+            .enumerate()
+            .fold(not_found, |pattern_position, (i, is_match)| {
+                let enc_i = FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
+                is_match.if_then_else(&self.key, &enc_i, &pattern_position)
+            })
+    }
+
+    /// Finds the last occurrence of a plaintext pattern in a given `FheString`.
+    ///
+    /// Same as `rfind` but with a plaintext pattern.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "hello abc abc test";
+    /// let needle_plain = "abc";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.rfind_clear(&heistack, &needle_plain, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 10u8);
+    /// ```
+    pub fn rfind_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("rfind_clear");
+        let pattern = clear_pattern
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        self.rfind(string.clone(), &pattern, public_parameters)
+    }
+
+    // The "easy" case
+    fn handle_longer_from(
+        bytes: FheString,
+        from: Vec<FheAsciiChar>,
+        mut to: Vec<FheAsciiChar>,
+        count: ReplaceCount,
+        server_key: &tfhe::integer::ServerKey,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, server_key);
+        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, server_key);
+
+        let bytes = bytes.terminated(public_parameters, server_key);
+
+        let size_difference = abs_difference(from.len(), to.len());
+        let mut counter = FheAsciiChar::encrypt_trivial(0u8, public_parameters, server_key);
+        let mut already_replaced = zero.clone();
+
+        // Pad to with zeroes
+        for _ in 0..size_difference {
+            to.push(zero.clone());
+        }
+
+        let mut result = bytes.clone();
+        // This is used to ignore matches that overlap an already replaced match
+        // For example when replacing aa in aaa only the first aa should be replaced
+        let mut ignore_pattern_mask = vec![one.clone(); result.len()];
+
+        // If from is longer than the string there is nothing to replace
+        if let Some(end_of_pattern) = result.len().checked_sub(from.len()) {
+            // If pattern and string have the same size and are equal
+            // this is needed to actually iterate the loop
+            let end_of_pattern = utils::adjust_end_of_pattern(end_of_pattern);
+
+            // Replace from wih to
+            for i in 0..end_of_pattern {
+                let mut pattern_found_flag = one.clone();
+
+                for j in 0..from.len() {
+                    pattern_found_flag = pattern_found_flag
+                        .bitand(server_key, &from[j].eq(server_key, &bytes[i + j]));
+                    pattern_found_flag =
+                        pattern_found_flag.bitand(server_key, &ignore_pattern_mask[i + j]);
+                }
+
+                // Stop replacing after the first or after n encounters of from
+                match &count {
+                    ReplaceCount::All => (),
+                    ReplaceCount::First => {
+                        pattern_found_flag = pattern_found_flag.bitand(
+                            server_key,
+                            &already_replaced.flip(server_key, public_parameters),
+                        );
+                        already_replaced = already_replaced.bitor(server_key, &pattern_found_flag);
+                    }
+                    ReplaceCount::AtMost(n) => {
+                        counter = counter.add(server_key, &pattern_found_flag);
+                        let keep_replacing = n.ge(server_key, &counter);
+                        pattern_found_flag = pattern_found_flag.bitand(server_key, &keep_replacing);
+                    }
+                }
+
+                for k in 0..to.len() {
+                    result[i + k] =
+                        pattern_found_flag.if_then_else(server_key, &to[k], &result[i + k]);
+                    ignore_pattern_mask[i + k] = ignore_pattern_mask[i + k].bitand(
+                        server_key,
+                        &pattern_found_flag.if_then_else(server_key, &zero, &one),
+                    );
+                }
+            }
+        }
+
+        utils::bubble_zeroes_right(result, server_key, public_parameters)
+    }
+
+    // The "hard" case
+    fn handle_shorter_from(
+        bytes: FheString,
+        from: Vec<FheAsciiChar>,
+        to: Vec<FheAsciiChar>,
+        count: ReplaceCount,
+        server_key: &tfhe::integer::ServerKey,
+        public_parameters: &PublicParameters,
     ) -> FheString {
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, server_key);
         let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, server_key);
 
-        // Quick solution to fix a no padding issue
-        bytes.push(zero.clone());
+        let bytes = bytes.terminated(public_parameters, server_key);
+
+        let size_difference = abs_difference(from.len(), to.len());
+        let mut counter = FheAsciiChar::encrypt_trivial(0u8, public_parameters, server_key);
+        let mut already_replaced = zero.clone();
+
+        let max_possible_output_len = if bytes.is_empty() {
+            to.len()
+        } else {
+            to.len() * bytes.len() + bytes.len()
+        };
+
+        // This implies that we match all characters
+        let max_possible_output_len = if from.is_empty() {
+            (bytes.len() + (bytes.len() + 1) * to.len()) + 1
+        } else {
+            max_possible_output_len
+        };
+
+        let mut result = bytes.clone();
+
+        for _ in 0..max_possible_output_len.saturating_sub(bytes.len()) {
+            result.push(zero.clone());
+        }
+
+        let mut copy_buffer = vec![zero.clone(); max_possible_output_len];
+        // This is used to ignore invalid pattern found flags
+        // This happens if for example we replace e with test, the e in test will match the pattern
+        // but its invalid
+        let mut ignore_pattern_mask = vec![one.clone(); max_possible_output_len];
+
+        // Replace from wih to
+        for i in 0..result.len().saturating_sub(to.len()) {
+            let mut pattern_found_flag = one.clone();
+
+            for j in 0..from.len() {
+                pattern_found_flag =
+                    pattern_found_flag.bitand(server_key, &from[j].eq(server_key, &result[i + j]));
+                pattern_found_flag =
+                    pattern_found_flag.bitand(server_key, &ignore_pattern_mask[i + j]);
+            }
+
+            // Handle spacial case where from is empty which means that it matches all characters
+            // I know its ugly but it works
+            if from.is_empty() {
+                if i % (to.len() + 1) == 0 {
+                    pattern_found_flag = one.clone();
+                } else {
+                    pattern_found_flag = zero.clone();
+                }
+            }
+
+            // Stop replacing after the first or after n encounters of from
+            match &count {
+                ReplaceCount::All => (),
+                ReplaceCount::First => {
+                    pattern_found_flag = pattern_found_flag.bitand(
+                        server_key,
+                        &already_replaced.flip(server_key, public_parameters),
+                    );
+                    already_replaced = already_replaced.bitor(server_key, &pattern_found_flag);
+                }
+                ReplaceCount::AtMost(n) => {
+                    counter = counter.add(server_key, &pattern_found_flag);
+                    let keep_replacing = n.ge(server_key, &counter);
+                    pattern_found_flag = pattern_found_flag.bitand(server_key, &keep_replacing);
+                }
+            }
+
+            // Copy original string to buffer
+            for k in 0..max_possible_output_len {
+                copy_buffer[k] = pattern_found_flag.if_then_else(server_key, &result[k], &zero);
+            }
+
+            // Replace from with to
+            for k in 0..to.len() {
+                result[i + k] = pattern_found_flag.if_then_else(server_key, &to[k], &result[i + k]);
+                ignore_pattern_mask[i + k] = ignore_pattern_mask[i + k].bitand(
+                    server_key,
+                    &pattern_found_flag.if_then_else(server_key, &zero, &one),
+                );
+            }
+
+            // Fix the result buffer by copying back the rest of the string
+            // k >= to.len() > size_difference so the index below can't underflow
+            for k in i + to.len()..max_possible_output_len {
+                result[k] = pattern_found_flag.if_then_else(
+                    server_key,
+                    &copy_buffer[k - size_difference],
+                    &result[k],
+                );
+            }
+        }
+        result
+    }
+
+    /// Finds the first occurrence of a pattern in a given `FheString`.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search.
+    /// * `pattern`: &Vec<FheAsciiChar> - The unpadded pattern to find.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted position of the first occurrence of the pattern,
+    ///  or encrypted MAX_FIND_LENGTH if not found
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "hello test";
+    /// let needle_plain = "test";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
+    /// let res = my_server_key.find(&heistack, &needle, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 6u8);
+    /// ```
+    pub fn find(
+        &self,
+        string: &FheString,
+        pattern: &Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("find");
+        if string.len() >= MAX_FIND_LENGTH + pattern.len() {
+            panic!("Maximum supported size for find reached");
+        }
+
+        let flags = self.pattern_match_flags(string, pattern, public_parameters);
+        self.first_match_position(flags, public_parameters)
+    }
+
+    // Returns the offset of the first set flag of `pattern_match_flags`, or MAX_FIND_LENGTH
+    fn first_match_position(
+        &self,
+        flags: Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        let not_found =
+            FheAsciiChar::encrypt_trivial(MAX_FIND_LENGTH as u8, public_parameters, &self.key);
+
+        let branches = flags
+            .into_iter()
+            .enumerate()
+            .map(|(i, is_match)| {
+                let enc_i = FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
+                (is_match, enc_i)
+            })
+            .collect::<Vec<(FheAsciiChar, FheAsciiChar)>>();
+
+        FheAsciiChar::select(&self.key, &branches, &not_found)
+    }
+
+    /// Finds the first occurrence of a plaintext pattern in a given `FheString`.
+    ///
+    /// Same as `find` but with a plaintext pattern.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "hello test";
+    /// let needle_plain = "test";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let res = my_server_key.find_clear(&heistack, &needle_plain, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 6u8);
+    /// ```
+    pub fn find_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("find_clear");
+        let pattern = clear_pattern
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        self.find(string, &pattern, public_parameters)
+    }
+
+    /// Finds the first occurrence of a pattern with a Rabin-Karp rolling hash.
+    ///
+    /// The hash of every window is rolled over the string and a position is a match where the
+    /// window hash equals the pattern hash and the full comparison of `find` agrees, so the
+    /// result is the same as `find`. On encrypted data a position where the hashes differ can't
+    /// be skipped without leaking it, so the full comparison still runs everywhere and the hash
+    /// can't prune the O(n * m) comparisons. examples/bench_find.rs compares the two on a long
+    /// haystack.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to find.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted position of the first occurrence of the pattern,
+    ///  or encrypted MAX_FIND_LENGTH if not found
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "hello test";
+    /// let needle_plain = "test";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
+    /// let res = my_server_key.find_rk(&heistack, &needle, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 6u8);
+    /// ```
+    pub fn find_rk(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("find_rk");
+        if string.len() >= MAX_FIND_LENGTH + pattern.len() {
+            panic!("Maximum supported size for find reached");
+        }
+
+        let Some(end_of_pattern) = string.len().checked_sub(pattern.len()) else {
+            return self.first_match_position(vec![], public_parameters);
+        };
+
+        const HASH_BASE: u8 = 31;
+        // Weight of the character leaving the window
+        let leading_weight = HASH_BASE.wrapping_pow(pattern.len().saturating_sub(1) as u32);
 
-        let size_difference = abs_difference(from.len(), to.len());
-        let mut counter = FheAsciiChar::encrypt_trivial(0u8, public_parameters, server_key);
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let hash = |chars: &[FheAsciiChar]| {
+            chars.iter().fold(zero.clone(), |hash, c| {
+                hash.scalar_mul(&self.key, HASH_BASE).add(&self.key, c)
+            })
+        };
 
-        // Pad to with zeroes
-        for _ in 0..size_difference {
-            to.push(zero.clone());
+        let pattern_hash = hash(pattern);
+        let mut window_hash = hash(&string[..pattern.len()]);
+
+        let match_flags = self.pattern_match_flags(string, pattern, public_parameters);
+        let mut flags = Vec::with_capacity(end_of_pattern + 1);
+        for (i, is_match) in match_flags.iter().enumerate() {
+            if i > 0 {
+                let leaving = string[i - 1].scalar_mul(&self.key, leading_weight);
+                window_hash = window_hash
+                    .sub(&self.key, &leaving)
+                    .scalar_mul(&self.key, HASH_BASE)
+                    .add(&self.key, &string[i + pattern.len() - 1]);
+            }
+            let hash_match = window_hash.eq(&self.key, &pattern_hash);
+            flags.push(hash_match.bitand(&self.key, is_match));
         }
 
-        let mut result = bytes.clone();
+        self.first_match_position(flags, public_parameters)
+    }
 
-        if from.len() <= result.len() {
-            // If pattern and string have the same size and are equal
-            // this is needed to actually iterate the loop
-            let end_of_pattern = utils::adjust_end_of_pattern(result.len() - from.len());
+    /// Finds the nth occurrence of a pattern in a given `FheString`.
+    ///
+    /// The occurrences are counted from 1 and do not overlap, like `s.match_indices(p).nth(n - 1)`,
+    /// so the second occurrence of `"aa"` in `"aaaa"` is at 2. An `n` of 0 is never found. This
+    /// is the match scan of `find` with an occurrence counter, useful to get to the nth field of
+    /// a string without a full split.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to find.
+    /// * `n`: &FheAsciiChar - The encrypted 1-based number of the occurrence.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted position of the nth occurrence of the pattern, or encrypted
+    /// MAX_FIND_LENGTH if there are fewer than n occurrences.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "a,b,c,d";
+    /// let needle_plain = ",";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
+    /// let n = my_client_key.encrypt_char(2u8);
+    ///
+    /// let res = my_server_key.find_nth(&heistack, &needle, &n, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 3u8);
+    /// ```
+    pub fn find_nth(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        n: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("find_nth");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let not_found =
+            FheAsciiChar::encrypt_trivial(MAX_FIND_LENGTH as u8, public_parameters, &self.key);
 
-            // Replace from wih to
-            for i in 0..end_of_pattern {
-                let mut pattern_found_flag = one.clone();
+        let mut occurrences = zero;
+        let mut branches = Vec::new();
 
-                for j in 0..from.len() {
-                    pattern_found_flag = pattern_found_flag
-                        .bitand(server_key, &from[j].eq(server_key, &bytes[i + j]));
-                }
+        for (i, is_match) in self
+            .non_overlapping_match_flags(string, pattern, public_parameters)
+            .into_iter()
+            .enumerate()
+        {
+            let enc_i = FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
 
-                // Stop replacing after n encounters of from
-                if use_counter {
-                    counter = counter.add(server_key, &pattern_found_flag);
-                    let keep_replacing = n.ge(server_key, &counter);
-                    pattern_found_flag = pattern_found_flag.bitand(server_key, &keep_replacing);
-                }
+            occurrences = occurrences.add(&self.key, &is_match);
 
-                for k in 0..to.len() {
-                    result[i + k] =
-                        pattern_found_flag.if_then_else(server_key, &to[k], &result[i + k]);
-                }
-            }
+            let is_nth = is_match.bitand(&self.key, &occurrences.eq(&self.key, n));
+            branches.push((is_nth, enc_i));
         }
 
-        utils::bubble_zeroes_right(result, server_key, public_parameters)
+        FheAsciiChar::select(&self.key, &branches, &not_found)
     }
 
-    // The "hard" case
-    fn handle_shorter_from(
-        mut bytes: FheString,
-        from: Vec<FheAsciiChar>,
-        to: Vec<FheAsciiChar>,
-        n: FheAsciiChar,
-        use_counter: bool,
-        server_key: &tfhe::integer::ServerKey,
+    /// Counts the non-overlapping occurrences of a pattern in a given `FheString`.
+    ///
+    /// Same as `s.matches(p).count()`: the occurrences are found from the left and do not
+    /// overlap, so `"aa"` occurs twice in `"aaaaa"`. The empty pattern occurs `len + 1` times, and
+    /// a pattern longer than the string never does.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search.
+    /// * `needle`: &[FheAsciiChar] - The unpadded pattern to count.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted number of occurrences of the pattern.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "zama is awesome, zama is fast";
+    /// let needle_plain = "zama";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
+    ///
+    /// let res = my_server_key.matches_count(&heistack, &needle, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 2u8);
+    /// ```
+    pub fn matches_count(
+        &self,
+        string: &FheString,
+        needle: &[FheAsciiChar],
         public_parameters: &PublicParameters,
-    ) -> FheString {
-        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, server_key);
-        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, server_key);
-
-        // Quick solution to fix a no padding issue
-        bytes.push(zero.clone());
+    ) -> FheAsciiChar {
+        time_method!("matches_count");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
-        let size_difference = abs_difference(from.len(), to.len());
-        let mut counter = FheAsciiChar::encrypt_trivial(0u8, public_parameters, server_key);
+        self.non_overlapping_match_flags(string, needle, public_parameters)
+            .iter()
+            .fold(zero, |count, is_match| count.add(&self.key, is_match))
+    }
 
-        let max_possible_output_len = if bytes.is_empty() {
-            to.len()
-        } else {
-            to.len() * bytes.len() + bytes.len()
-        };
+    /// Counts the non-overlapping occurrences of a plaintext pattern in a given `FheString`.
+    ///
+    /// Same as `matches_count` but with plaintext pattern.
+    pub fn matches_count_clear(
+        &self,
+        string: &FheString,
+        clear_needle: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("matches_count_clear");
+        let needle = clear_needle
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
 
-        // This implies that we match all characters
-        let max_possible_output_len = if from.is_empty() {
-            (bytes.len() + (bytes.len() + 1) * to.len()) + 1
-        } else {
-            max_possible_output_len
-        };
+        self.matches_count(string, &needle, public_parameters)
+    }
 
-        let mut result = bytes.clone();
+    /// Checks if a given `FheString` contains a specified pattern and finds the position of its
+    /// first occurrence, with a single scan of the string.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search.
+    /// * `pattern`: &Vec<FheAsciiChar> - The unpadded pattern to find.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `(FheAsciiChar, FheAsciiChar)` - Encrypted 1 if the pattern is found, otherwise encrypted 0
+    /// (same as `contains`), and the encrypted position of the first occurrence of the pattern,
+    /// or encrypted MAX_FIND_LENGTH if not found (same as `find`).
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "hello test test";
+    /// let needle_plain = "test";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
+    /// let (found, position) =
+    ///     my_server_key.contains_with_position(&heistack, &needle, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&found), 1u8);
+    /// assert_eq!(my_client_key.decrypt_char(&position), 6u8);
+    /// ```
+    pub fn contains_with_position(
+        &self,
+        string: &FheString,
+        pattern: &Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+    ) -> (FheAsciiChar, FheAsciiChar) {
+        time_method!("contains_with_position");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
-        for _ in 0..max_possible_output_len - bytes.len() {
-            result.push(zero.clone());
+        if string.len() >= MAX_FIND_LENGTH + pattern.len() {
+            panic!("Maximum supported size for find reached");
         }
 
-        let mut copy_buffer = vec![zero.clone(); max_possible_output_len];
-        // This is used to ignore invalid pattern found flags
-        // This happens if for example we replace e with test, the e in test will match the pattern
-        // but its invalid
-        let mut ignore_pattern_mask = vec![one.clone(); max_possible_output_len];
+        let flags = self.pattern_match_flags(string, pattern, public_parameters);
 
-        // Replace from wih to
-        for i in 0..result.len() - to.len() {
-            let mut pattern_found_flag = one.clone();
+        let pattern_found = flags
+            .iter()
+            .fold(zero, |result, is_match| result.bitor(&self.key, is_match));
+        let pattern_position = self.first_match_position(flags, public_parameters);
 
-            for j in 0..from.len() {
-                pattern_found_flag =
-                    pattern_found_flag.bitand(server_key, &from[j].eq(server_key, &result[i + j]));
-                pattern_found_flag =
-                    pattern_found_flag.bitand(server_key, &ignore_pattern_mask[i + j]);
-            }
+        (pattern_found, pattern_position)
+    }
 
-            // Handle spacial case where from is empty which means that it matches all characters
-            // I know its ugly but it works
-            if from.is_empty() {
-                if i % (to.len() + 1) == 0 {
-                    pattern_found_flag = one.clone();
-                } else {
-                    pattern_found_flag = zero.clone();
-                }
-            }
+    /// Checks if a given `FheString` contains a specified plaintext pattern and finds the position
+    /// of its first occurrence, with a single scan of the string.
+    ///
+    /// Same as `contains_with_position` but with a plaintext pattern.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "hello test test";
+    /// let needle_plain = "test";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let (found, position) =
+    ///     my_server_key.contains_with_position_clear(&heistack, &needle_plain, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&found), 1u8);
+    /// assert_eq!(my_client_key.decrypt_char(&position), 6u8);
+    /// ```
+    pub fn contains_with_position_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        public_parameters: &PublicParameters,
+    ) -> (FheAsciiChar, FheAsciiChar) {
+        time_method!("contains_with_position_clear");
+        let pattern = clear_pattern
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
 
-            // Stop replacing after n encounters of from
-            if use_counter {
-                counter = counter.add(server_key, &pattern_found_flag);
-                let keep_replacing = n.ge(server_key, &counter);
-                pattern_found_flag = pattern_found_flag.bitand(server_key, &keep_replacing);
-            }
+        self.contains_with_position(string, &pattern, public_parameters)
+    }
 
-            // Copy original string to buffer
-            for k in 0..max_possible_output_len {
-                copy_buffer[k] = pattern_found_flag.if_then_else(server_key, &result[k], &zero);
-            }
+    /// Checks if two `FheString` instances are equal.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The first string to compare.
+    /// * `other`: &FheString - The second string to compare.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - Encrypted 1 if strings are equal, otherwise encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack1_plain = "hello test";
+    /// let heistack2_plain = "hello test";
+    ///
+    /// let heistack1 = my_client_key.encrypt(
+    ///     heistack1_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let heistack2 = my_client_key.encrypt(
+    ///     heistack2_plain,
+    ///     STRING_PADDING + 20,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.eq(&heistack1, &heistack2, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
+    /// ```
+    pub fn eq(
+        &self,
+        string: &FheString,
+        other: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("eq");
+        let min_length = usize::min(string.len(), other.len());
 
-            // Replace from with to
-            for k in 0..to.len() {
-                result[i + k] = pattern_found_flag.if_then_else(server_key, &to[k], &result[i + k]);
-                ignore_pattern_mask[i + k] = ignore_pattern_mask[i + k].bitand(
-                    server_key,
-                    &pattern_found_flag.if_then_else(server_key, &zero, &one),
-                );
-            }
+        let len1 = self.len(string, public_parameters);
+        let len2 = self.len(other, public_parameters);
+
+        // If strings have actual lengths that are not equal then they can never be equal.
+        // Otherwise both strings have padding at the same positions, where the characters are
+        // equal anyway, so only the characters need to be compared. Past `min_length` the longer
+        // string can only hold padding
+        let mut is_eq = len1.eq(&self.key, &len2);
 
-            // Fix the result buffer by copying back the rest of the string
-            for k in i + to.len()..max_possible_output_len {
-                result[k] = pattern_found_flag.if_then_else(
-                    server_key,
-                    &copy_buffer[k - size_difference],
-                    &result[k],
-                );
-            }
+        for i in 0..min_length {
+            let are_equal = string[i].eq(&self.key, &other[i]);
+            is_eq = is_eq.bitand(&self.key, &are_equal);
         }
-        result
+
+        is_eq
     }
 
-    /// Finds the first occurrence of a pattern in a given `FheString`.
+    /// Checks if two `FheString` instances are not equal.
+    ///
+    /// Same as `eq` but returns true if strings are not equal.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack1_plain = "hello test";
+    /// let heistack2_plain = "hello test";
+    ///
+    /// let heistack1 = my_client_key.encrypt(
+    ///     heistack1_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let heistack2 = my_client_key.encrypt(
+    ///     heistack2_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.ne(&heistack1, &heistack2, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 0u8);
+    /// ```
+    pub fn ne(
+        &self,
+        string: &FheString,
+        other: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("ne");
+        let res = self.eq(string, other, public_parameters);
+        res.flip(&self.key, public_parameters)
+    }
+
+    /// Checks if a given `FheString` is equal to any of several strings, like checking that an
+    /// encrypted status is one of the allowed values.
     ///
     /// # Arguments
-    /// * `string`: &FheString - The string to search.
-    /// * `pattern`: &Vec<FheAsciiChar> - The unpadded pattern to find.
+    /// * `string`: &FheString - The string to check.
+    /// * `candidates`: &[FheString] - The strings to compare against.
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
-    /// `FheAsciiChar` - The encrypted position of the first occurrence of the pattern,
-    ///  or encrypted MAX_FIND_LENGTH if not found
+    /// `FheAsciiChar` - Encrypted 1 if the string is equal to one of the candidates, otherwise
+    /// encrypted 0.
     ///
     /// # Example:
     /// ```
-    /// let heistack_plain = "hello test";
-    /// let needle_plain = "test";
+    /// let status_plain = "done";
     ///
-    /// let heistack = my_client_key.encrypt(
-    ///     heistack_plain,
+    /// let status = my_client_key.encrypt(
+    ///     status_plain,
     ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
-    /// let needle = my_client_key.encrypt_no_padding(needle_plain);
-    /// let res = my_server_key.find(&heistack, &needle, &public_parameters);
+    /// let allowed = ["todo", "doing", "done"]
+    ///     .iter()
+    ///     .map(|s| my_client_key.encrypt_auto(s, &public_parameters, &my_server_key.key))
+    ///     .collect::<Vec<FheString>>();
+    ///
+    /// let res = my_server_key.equals_any(&status, &allowed, &public_parameters);
     /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(dec, 6u8);
+    /// assert_eq!(dec, 1u8);
     /// ```
-    pub fn find(
+    pub fn equals_any(
         &self,
         string: &FheString,
-        pattern: &Vec<FheAsciiChar>,
+        candidates: &[FheString],
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
-        // Edge case: If both are empty return found at position 0
-        if string.is_empty() && pattern.is_empty() {
-            return FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
-        }
-
-        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
-        let mut pattern_position =
-            FheAsciiChar::encrypt_trivial(MAX_FIND_LENGTH as u8, public_parameters, &self.key);
+        time_method!("equals_any");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
-        if string.len() >= MAX_FIND_LENGTH + pattern.len() {
-            panic!("Maximum supported size for find reached");
-        }
+        candidates.iter().fold(zero, |result, candidate| {
+            result.bitor(&self.key, &self.eq(string, candidate, public_parameters))
+        })
+    }
 
-        let end = string.len().checked_sub(pattern.len());
+    /// Finds which of several strings a given `FheString` is equal to.
+    ///
+    /// Same as `equals_any` but returns the encrypted index of the first equal candidate, or
+    /// encrypted MAX_FIND_LENGTH if there is none.
+    ///
+    /// # Example:
+    /// ```
+    /// let status_plain = "done";
+    ///
+    /// let status = my_client_key.encrypt(
+    ///     status_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let allowed = ["todo", "doing", "done"]
+    ///     .iter()
+    ///     .map(|s| my_client_key.encrypt_auto(s, &public_parameters, &my_server_key.key))
+    ///     .collect::<Vec<FheString>>();
+    ///
+    /// let res = my_server_key.which_equals(&status, &allowed, &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 2u8);
+    /// ```
+    pub fn which_equals(
+        &self,
+        string: &FheString,
+        candidates: &[FheString],
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("which_equals");
+        let not_found =
+            FheAsciiChar::encrypt_trivial(MAX_FIND_LENGTH as u8, public_parameters, &self.key);
 
-        match end {
-            Some(end_of_pattern) => {
-                // Search for pattern
-                for i in (0..=end_of_pattern).rev() {
-                    let mut pattern_found_flag = one.clone();
-
-                    // This is okay since the pattern here is <= string.bytes.len()
-                    for j in (0..pattern.len()).rev() {
-                        pattern_found_flag = pattern_found_flag
-                            .bitand(&self.key, &pattern[j].eq(&self.key, &string[i + j]));
-                    }
+        let branches = candidates
+            .iter()
+            .enumerate()
+            .map(|(k, candidate)| {
+                let enc_k = FheAsciiChar::encrypt_trivial(k as u8, public_parameters, &self.key);
+                (self.eq(string, candidate, public_parameters), enc_k)
+            })
+            .collect::<Vec<(FheAsciiChar, FheAsciiChar)>>();
 
-                    let enc_i =
-                        FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
-                    pattern_position =
-                        pattern_found_flag.if_then_else(&self.key, &enc_i, &pattern_position);
-                }
+        FheAsciiChar::select(&self.key, &branches, &not_found)
+    }
 
-                pattern_position
-            }
-            None => FheAsciiChar::encrypt_trivial(255u8, public_parameters, &self.key),
-        }
+    /// Compares two `FheString` instances position by position.
+    ///
+    /// The output has one flag per position of the overlapping length, so its length is
+    /// `min(string.len(), other.len())` whatever the actual contents. Padding is compared like any
+    /// other character, so a position where only one of the strings has ended is a difference.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The first string to compare.
+    /// * `other`: &FheString - The second string to compare.
+    ///
+    /// # Returns
+    /// `Vec<FheAsciiChar>` - Encrypted 1 at the positions where the strings differ, otherwise
+    /// encrypted 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack1_plain = "kitten";
+    /// let heistack2_plain = "sitten";
+    ///
+    /// let heistack1 = my_client_key.encrypt(
+    ///     heistack1_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let heistack2 = my_client_key.encrypt(
+    ///     heistack2_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.diff_positions(&heistack1, &heistack2);
+    /// let dec = res
+    ///     .iter()
+    ///     .map(|c| my_client_key.decrypt_char(c))
+    ///     .collect::<Vec<u8>>();
+    ///
+    /// assert_eq!(dec, vec![1, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    pub fn diff_positions(&self, string: &FheString, other: &FheString) -> Vec<FheAsciiChar> {
+        time_method!("diff_positions");
+        string
+            .iter()
+            .zip(other.iter())
+            .map(|(a, b)| a.ne(&self.key, b))
+            .collect()
     }
 
-    /// Finds the first occurrence of a plaintext pattern in a given `FheString`.
+    /// Computes the length of the longest common prefix of two `FheString` instances.
     ///
-    /// Same as `find` but with a plaintext pattern.
+    /// Both strings are scanned over their overlapping length, a flag records whether all the
+    /// previous characters matched and the counter is only incremented while it is set. Padding
+    /// never matches, so the strings can have different paddings.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The first string.
+    /// * `other`: &FheString - The second string.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - The encrypted length of the common prefix.
     ///
     /// # Example:
     /// ```
-    /// let heistack_plain = "hello test";
-    /// let needle_plain = "test";
+    /// let heistack1_plain = "abcX";
+    /// let heistack2_plain = "abcY";
     ///
-    /// let heistack = my_client_key.encrypt(
-    ///     heistack_plain,
+    /// let heistack1 = my_client_key.encrypt(
+    ///     heistack1_plain,
     ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
-    /// let res = my_server_key.find_clear(&heistack, &needle_plain, &public_parameters);
+    /// let heistack2 = my_client_key.encrypt(
+    ///     heistack2_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.common_prefix_len(&heistack1, &heistack2, &public_parameters);
     /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(dec, 6u8);
+    /// assert_eq!(dec, 3u8);
     /// ```
-    pub fn find_clear(
+    pub fn common_prefix_len(
         &self,
         string: &FheString,
-        clear_pattern: &str,
+        other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
-        let pattern = clear_pattern
-            .bytes()
-            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
-            .collect::<Vec<FheAsciiChar>>();
+        time_method!("common_prefix_len");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
 
-        self.find(string, &pattern, public_parameters)
+        let mut still_matching = one;
+        let mut result = zero.clone();
+
+        for (a, b) in string.iter().zip(other.iter()) {
+            let is_eq = a.eq(&self.key, b);
+            let is_not_zero = a.ne(&self.key, &zero);
+
+            still_matching = still_matching
+                .bitand(&self.key, &is_eq)
+                .bitand(&self.key, &is_not_zero);
+            result = result.add(&self.key, &still_matching);
+        }
+
+        result
     }
 
-    /// Checks if two `FheString` instances are equal.
+    /// Computes the length of the longest common suffix of two `FheString` instances.
+    ///
+    /// The visible ends of the strings are not known, so for every distance `k` from the end the
+    /// kth last character of each string is selected using its encrypted length, which costs
+    /// O(n^2) comparisons. The characters are then compared like in `common_prefix_len`.
     ///
     /// # Arguments
-    /// * `string`: &FheString - The first string to compare.
-    /// * `other`: &FheString - The second string to compare.
+    /// * `string`: &FheString - The first string.
+    /// * `other`: &FheString - The second string.
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
-    /// `FheAsciiChar` - Encrypted 1 if strings are equal, otherwise encrypted 0.
+    /// `FheAsciiChar` - The encrypted length of the common suffix.
     ///
     /// # Example:
     /// ```
-    /// let heistack1_plain = "hello test";
-    /// let heistack2_plain = "hello test";
+    /// let heistack1_plain = "Xyz";
+    /// let heistack2_plain = "Wyz";
     ///
     /// let heistack1 = my_client_key.encrypt(
     ///     heistack1_plain,
@@ -1109,52 +3909,83 @@ impl MyServerKey {
     /// );
     /// let heistack2 = my_client_key.encrypt(
     ///     heistack2_plain,
-    ///     STRING_PADDING + 20,
+    ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
     ///
-    /// let res = my_server_key.eq(&heistack1, &heistack2, &public_parameters);
+    /// let res = my_server_key.common_suffix_len(&heistack1, &heistack2, &public_parameters);
     /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(dec, 1u8);
+    /// assert_eq!(dec, 2u8);
     /// ```
-    pub fn eq(
+    pub fn common_suffix_len(
         &self,
         string: &FheString,
         other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("common_suffix_len");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
         let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
-        let mut is_eq = one.clone();
-        let min_length = usize::min(string.len(), other.len());
 
-        let len1 = self.len(string, public_parameters);
-        let len2 = self.len(other, public_parameters);
-        let are_lengths_not_eql = len1.ne(&self.key, &len2);
+        let string_len = self.len(string, public_parameters);
+        let other_len = self.len(other, public_parameters);
 
-        for i in 0..min_length {
-            let are_equal = string[i].eq(&self.key, &other[i]);
-            let is_first_eq_zero = string[i].eq(&self.key, &zero);
-            let is_second_eq_zero = other[i].eq(&self.key, &zero);
+        let mut still_matching = one;
+        let mut result = zero.clone();
+
+        for k in 0..usize::min(string.len(), other.len()) {
+            let a = self.nth_from_end(string, &string_len, k, public_parameters);
+            let b = self.nth_from_end(other, &other_len, k, public_parameters);
+
+            // Past the start of a string the selected character is \0, which never matches
+            let is_eq = a.eq(&self.key, &b);
+            let is_not_zero = a.ne(&self.key, &zero);
 
-            let res = is_first_eq_zero.bitand(&self.key, &is_second_eq_zero);
-            let res = res.bitor(&self.key, &are_equal);
+            still_matching = still_matching
+                .bitand(&self.key, &is_eq)
+                .bitand(&self.key, &is_not_zero);
+            result = result.add(&self.key, &still_matching);
+        }
+
+        result
+    }
+
+    // Returns the kth character from the end of the string, or \0 if the string is shorter
+    fn nth_from_end(
+        &self,
+        string: &FheString,
+        string_len: &FheAsciiChar,
+        k: usize,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        let mut result = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
-            is_eq = is_eq.bitand(&self.key, &res);
+        // The character at i is the kth from the end when i + 1 + k is the length
+        for i in 0..string.len().saturating_sub(k) {
+            let enc_end =
+                FheAsciiChar::encrypt_trivial((i + 1 + k) as u8, public_parameters, &self.key);
+            let is_kth_from_end = string_len.eq(&self.key, &enc_end);
+            result = is_kth_from_end.if_then_else(&self.key, &string[i], &result);
         }
-        // If strings have actual lengths that are not equal then they can never be equal
-        are_lengths_not_eql.if_then_else(&self.key, &zero, &is_eq)
+
+        result
     }
 
-    /// Checks if two `FheString` instances are not equal.
+    /// Checks if two `FheString` instances are equal, ignoring case.
     ///
-    /// Same as `eq` but returns true if strings are not equal.
+    /// # Arguments
+    /// * `string`: &FheString - The first string to compare.
+    /// * `other`: &FheString - The second string to compare.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheAsciiChar` - Encrypted 1 if strings are equal ignoring case, otherwise encrypted 0.
     ///
     /// # Example:
     /// ```
-    /// let heistack1_plain = "hello test";
+    /// let heistack1_plain = "hello TEST";
     /// let heistack2_plain = "hello test";
     ///
     /// let heistack1 = my_client_key.encrypt(
@@ -1165,27 +3996,72 @@ impl MyServerKey {
     /// );
     /// let heistack2 = my_client_key.encrypt(
     ///     heistack2_plain,
-    ///     STRING_PADDING,
+    ///     STRING_PADDING + 20,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
     ///
-    /// let res = my_server_key.ne(&heistack1, &heistack2, &public_parameters);
+    /// let res = my_server_key.eq_ignore_case(&heistack1, &heistack2, &public_parameters);
     /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
-    /// assert_eq!(dec, 0u8);
+    /// assert_eq!(dec, 1u8);
     /// ```
-    pub fn ne(
+    pub fn eq_ignore_case(
         &self,
         string: &FheString,
         other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
-        let res = self.eq(string, other, public_parameters);
-        res.flip(&self.key, public_parameters)
+        time_method!("eq_ignore_case");
+        let self_lowercase = self.to_lower(string, public_parameters);
+        let other_lowercase = self.to_lower(other, public_parameters);
+
+        self.eq(&self_lowercase, &other_lowercase, public_parameters)
+    }
+
+    /// Checks if a `FheString` is equal to a plaintext string, ignoring case.
+    ///
+    /// Same as `eq_ignore_case` but with a plaintext string, which is lowercased before being
+    /// trivially encrypted.
+    ///
+    /// # Example:
+    /// ```
+    /// let heistack_plain = "Hello";
+    ///
+    /// let heistack = my_client_key.encrypt(
+    ///     heistack_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.eq_ignore_case_clear(&heistack, "hello", &public_parameters);
+    /// let dec: u8 = my_client_key.decrypt_char(&res);
+    ///
+    /// assert_eq!(dec, 1u8);
+    /// ```
+    pub fn eq_ignore_case_clear(
+        &self,
+        string: &FheString,
+        clear_other: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        time_method!("eq_ignore_case_clear");
+        let other_lowercase = clear_other
+            .to_ascii_lowercase()
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+        let other_lowercase = FheString::from_vec(other_lowercase, public_parameters, &self.key);
+        let self_lowercase = self.to_lower(string, public_parameters);
+
+        self.eq(&self_lowercase, &other_lowercase, public_parameters)
     }
 
-    /// Checks if two `FheString` instances are equal, ignoring case.
+    /// Checks if two `FheString` instances are equal, ignoring leading and trailing whitespace.
+    ///
+    /// Both strings are trimmed before being compared, `eq` compares the actual lengths so the
+    /// strings can have different paddings.
     ///
     /// # Arguments
     /// * `string`: &FheString - The first string to compare.
@@ -1193,12 +4069,12 @@ impl MyServerKey {
     /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
     ///
     /// # Returns
-    /// `FheAsciiChar` - Encrypted 1 if strings are equal ignoring case, otherwise encrypted 0.
+    /// `FheAsciiChar` - Encrypted 1 if the trimmed strings are equal, otherwise encrypted 0.
     ///
     /// # Example:
     /// ```
-    /// let heistack1_plain = "hello TEST";
-    /// let heistack2_plain = "hello test";
+    /// let heistack1_plain = "  hi ";
+    /// let heistack2_plain = "hi";
     ///
     /// let heistack1 = my_client_key.encrypt(
     ///     heistack1_plain,
@@ -1208,26 +4084,27 @@ impl MyServerKey {
     /// );
     /// let heistack2 = my_client_key.encrypt(
     ///     heistack2_plain,
-    ///     STRING_PADDING + 20,
+    ///     STRING_PADDING,
     ///     &public_parameters,
     ///     &my_server_key.key,
     /// );
     ///
-    /// let res = my_server_key.eq_ignore_case(&heistack1, &heistack2, &public_parameters);
+    /// let res = my_server_key.eq_trimmed(&heistack1, &heistack2, &public_parameters);
     /// let dec: u8 = my_client_key.decrypt_char(&res);
     ///
     /// assert_eq!(dec, 1u8);
     /// ```
-    pub fn eq_ignore_case(
+    pub fn eq_trimmed(
         &self,
         string: &FheString,
         other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
-        let self_lowercase = self.to_lower(string, public_parameters);
-        let other_lowercase = self.to_lower(other, public_parameters);
+        time_method!("eq_trimmed");
+        let string_trimmed = self.trim(string, public_parameters);
+        let other_trimmed = self.trim(other, public_parameters);
 
-        self.eq(&self_lowercase, &other_lowercase, public_parameters)
+        self.eq(&string_trimmed, &other_trimmed, public_parameters)
     }
 
     /// Strips a specified pattern from the beginning of a `FheString`.
@@ -1264,6 +4141,7 @@ impl MyServerKey {
         pattern: &Vec<FheAsciiChar>,
         public_parameters: &PublicParameters,
     ) -> FheStrip {
+        time_method!("strip_prefix");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
         let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
         let mut result = string.clone();
@@ -1338,6 +4216,7 @@ impl MyServerKey {
         needle: &Vec<FheAsciiChar>,
         public_parameters: &PublicParameters,
     ) -> FheStrip {
+        time_method!("strip_suffix");
         let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
         let end = string.len().checked_sub(needle.len());
@@ -1346,23 +4225,20 @@ impl MyServerKey {
         let mut pattern_position =
             FheAsciiChar::encrypt_trivial(255u8, public_parameters, &self.key);
 
+        // The empty pattern matches at every offset
+        let pattern_flags = self.pattern_match_flags(&string, needle, public_parameters);
+
         match end {
             Some(end_of_pattern) => {
-                for i in 0..=end_of_pattern {
-                    let mut pattern_found = one.clone();
-                    let mut are_all_comparison_chars_non_zero = one.clone();
+                for (i, pattern_found) in pattern_flags.iter().enumerate() {
                     let enc_i =
                         FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
 
-                    for (j, needle_char) in needle.iter().enumerate() {
-                        let eql = string[i + j].eq(&self.key, needle_char);
-                        pattern_found = pattern_found.bitand(&self.key, &eql);
-
-                        // If we encounter padding we should ignore the result
-                        let is_char_not_zero = string[i + j].ne(&self.key, &zero);
-                        are_all_comparison_chars_non_zero =
-                            are_all_comparison_chars_non_zero.bitand(&self.key, &is_char_not_zero);
-                    }
+                    // If we encounter padding we should ignore the result
+                    let are_all_comparison_chars_non_zero =
+                        (i..i + needle.len()).fold(one.clone(), |are_non_zero, k| {
+                            are_non_zero.bitand(&self.key, &string[k].ne(&self.key, &zero))
+                        });
 
                     let current_result =
                         pattern_found.if_then_else(&self.key, &enc_i, &two_five_five);
@@ -1424,6 +4300,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheStrip {
+        time_method!("strip_prefix_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -1460,6 +4337,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheStrip {
+        time_method!("strip_suffix_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -1467,6 +4345,10 @@ impl MyServerKey {
         self.strip_suffix(string.clone(), &pattern, public_parameters)
     }
 
+    // Compares the strings like `str` does whatever their capacities are. The first differing
+    // position decides, and since the padding \0 is smaller than every character a string that
+    // is a prefix of the other one is smaller. If one of them has no capacity left there is no
+    // such position, then the lengths break the tie
     fn comparison(
         &self,
         string: &FheString,
@@ -1488,8 +4370,8 @@ impl MyServerKey {
 
         // Edge case workaround, this happens if strings are unpadded
         if min_length == 0 {
-            string_clone.push(zero.clone());
-            other_clone.push(zero.clone());
+            string_clone = string_clone.terminated(public_parameters, &self.key);
+            other_clone = other_clone.terminated(public_parameters, &self.key);
             min_length = 1;
         }
 
@@ -1542,6 +4424,9 @@ impl MyServerKey {
 
     /// Checks if the first `FheString` is less than the second `FheString`.
     ///
+    /// The strings are compared lexicographically like `str`, the padding is smaller than every
+    /// character so `"ab"` is less than `"abc"` whatever the capacities of the two strings are.
+    ///
     /// # Arguments
     /// * `string`: &FheString - The first string to compare.
     /// * `other`: &FheString - The second string to compare.
@@ -1580,6 +4465,7 @@ impl MyServerKey {
         other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("lt");
         self.comparison(string, other, Comparison::LessThan, public_parameters)
     }
 
@@ -1616,6 +4502,7 @@ impl MyServerKey {
         other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("le");
         self.comparison(string, other, Comparison::LessEqual, public_parameters)
     }
 
@@ -1652,6 +4539,7 @@ impl MyServerKey {
         other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("gt");
         self.comparison(string, other, Comparison::GreaterThan, public_parameters)
     }
 
@@ -1688,6 +4576,7 @@ impl MyServerKey {
         other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheAsciiChar {
+        time_method!("ge");
         self.comparison(string, other, Comparison::GreaterEqual, public_parameters)
     }
 
@@ -1734,13 +4623,13 @@ impl MyServerKey {
         n: FheAsciiChar,
         public_parameters: &PublicParameters,
     ) -> FheString {
+        time_method!("replacen");
         if from.len() >= to.len() {
             Self::handle_longer_from(
                 string.clone(),
                 from.clone(),
                 to.clone(),
-                n,
-                true,
+                ReplaceCount::AtMost(n),
                 &self.key,
                 public_parameters,
             )
@@ -1749,8 +4638,7 @@ impl MyServerKey {
                 string.clone(),
                 from.clone(),
                 to.clone(),
-                n,
-                true,
+                ReplaceCount::AtMost(n),
                 &self.key,
                 public_parameters,
             )
@@ -1794,6 +4682,7 @@ impl MyServerKey {
         n_clear: u8,
         public_parameters: &PublicParameters,
     ) -> FheString {
+        time_method!("replacen_clear");
         let from = from_clear
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -1811,8 +4700,7 @@ impl MyServerKey {
                 string.clone(),
                 from.clone(),
                 to.clone(),
-                n,
-                true,
+                ReplaceCount::AtMost(n),
                 &self.key,
                 public_parameters,
             )
@@ -1821,16 +4709,94 @@ impl MyServerKey {
                 string.clone(),
                 from.clone(),
                 to.clone(),
-                n,
-                true,
+                ReplaceCount::AtMost(n),
                 &self.key,
                 public_parameters,
             )
         }
     }
 
+    /// Pads the content of a given `FheString` with `fill` up to the next multiple of `block`,
+    /// for schemes that process fixed size blocks.
+    ///
+    /// The padded content is never longer than the string rounded up to a multiple of `block`,
+    /// so the result has that capacity. A content whose length is already a multiple of `block`,
+    /// including the empty one, is left as is. `fill` should not be \0, which would leave the
+    /// string unchanged.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to pad.
+    /// * `block`: usize - The block size, at least 1.
+    /// * `fill`: FheAsciiChar - The character to pad with.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The padded string.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abcde";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let fill = my_client_key.encrypt_char(b'#');
+    /// let padded = my_server_key.pad_to_block_multiple(&my_string, 4, fill, &public_parameters);
+    /// let actual = my_client_key.decrypt(padded);
+    ///
+    /// assert_eq!(actual, "abcde###");
+    /// ```
+    pub fn pad_to_block_multiple(
+        &self,
+        string: &FheString,
+        block: usize,
+        fill: FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("pad_to_block_multiple");
+        assert!(block >= 1, "The block size must be at least 1");
+
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let string_len = self.len(string, public_parameters);
+
+        let max_padded_len = string.len().div_ceil(block) * block;
+        let mut result = string.get_bytes();
+        result.resize(max_padded_len, zero.clone());
+
+        for (block_start, block_chars) in (0..max_padded_len)
+            .step_by(block)
+            .zip(result.chunks_mut(block))
+        {
+            // An encrypted length can't reach past 255, so neither can the used blocks
+            let Ok(block_start) = u8::try_from(block_start) else {
+                break;
+            };
+
+            // The block is filled up to its end if the content starts in it or before, and the
+            // content is in front of the padding so only the \0 characters are replaced
+            let is_block_used = string_len.scalar_gt(&self.key, block_start);
+
+            for block_char in block_chars.iter_mut() {
+                let should_fill = is_block_used.bitand(&self.key, &block_char.eq(&self.key, &zero));
+                *block_char = should_fill.if_then_else(&self.key, &fill, block_char);
+            }
+        }
+
+        FheString::from_vec(result, public_parameters, &self.key)
+    }
+
     /// Concatenates two `FheString` instances into one.
     ///
+    /// Both strings are expected in the canonical `[content..., padding...]` layout, which is
+    /// the case for encrypted strings and for the results of the `MyServerKey` methods. Then the
+    /// only \0 characters to remove are the padding of `string`, so instead of bubbling the
+    /// whole result, `other` is shifted left over that padding in O(n log n). An encrypted
+    /// character only counts up to 255, so the padding of a longer `string` is counted and
+    /// shifted over in chunks of 255 characters.
+    ///
     /// # Arguments
     /// * `string`: &FheString - The first string to concatenate.
     /// * `other`: &FheString - The second string to concatenate.
@@ -1867,10 +4833,463 @@ impl MyServerKey {
         other: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheString {
-        let mut result = string.clone();
-        let clone_other = other.clone();
+        time_method!("concatenate");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
-        result.append(clone_other);
-        utils::bubble_zeroes_right(result, &self.key, public_parameters)
+        // Place other right after the first string, then shift it left over the padding of each
+        // chunk of the first string, together the shifts add up to its whole padding
+        let mut result = vec![zero.clone(); string.len()];
+        result.append(&mut other.get_bytes());
+        for chunk in string[..string.len()].chunks(u8::MAX as usize) {
+            let padding_len = chunk.iter().fold(zero.clone(), |padding_len, string_char| {
+                padding_len.add(&self.key, &string_char.eq(&self.key, &zero))
+            });
+            result = self.shift_left(result, &padding_len, chunk.len(), public_parameters);
+        }
+
+        // The shifted content starts where the content of the first string ends so they don't
+        // overlap, and adding them merges the two
+        for (k, string_char) in string.iter().enumerate() {
+            result[k] = result[k].add(&self.key, string_char);
+        }
+
+        FheString::from_vec(result, public_parameters, &self.key)
+    }
+
+    // Shifts the characters left by an encrypted amount of at most max_shift, filling the end
+    // with \0. The shift is done one power of two at a time, so this is O(n log(max_shift)).
+    // The encrypted amount is at most 255 whatever the caller passes as max_shift
+    fn shift_left(
+        &self,
+        mut bytes: Vec<FheAsciiChar>,
+        shift: &FheAsciiChar,
+        max_shift: usize,
+        public_parameters: &PublicParameters,
+    ) -> Vec<FheAsciiChar> {
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let total_len = bytes.len();
+
+        let mut remaining_shift = shift.clone();
+        // A step of 256 or more would be encrypted as its low byte
+        let max_shift = max_shift.min(u8::MAX as usize);
+        let shift_bits = usize::BITS - max_shift.leading_zeros();
+
+        for bit in (0..shift_bits).rev() {
+            let step = 1usize << bit;
+            let enc_step = FheAsciiChar::encrypt_trivial(step as u8, public_parameters, &self.key);
+            let should_shift = remaining_shift.ge(&self.key, &enc_step);
+            remaining_shift = should_shift.if_then_else(
+                &self.key,
+                &remaining_shift.sub(&self.key, &enc_step),
+                &remaining_shift,
+            );
+
+            // Going forward only reads characters that have not been shifted yet
+            for k in 0..total_len {
+                let next_char = if k + step < total_len {
+                    bytes[k + step].clone()
+                } else {
+                    zero.clone()
+                };
+                bytes[k] = should_shift.if_then_else(&self.key, &next_char, &bytes[k]);
+            }
+        }
+
+        bytes
+    }
+
+    // Shifts the characters right by an encrypted amount of at most max_shift, filling the start
+    // with \0 and dropping what goes past the end. Same as shift_left in the other direction
+    fn shift_right(
+        &self,
+        mut bytes: Vec<FheAsciiChar>,
+        shift: &FheAsciiChar,
+        max_shift: usize,
+        public_parameters: &PublicParameters,
+    ) -> Vec<FheAsciiChar> {
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let total_len = bytes.len();
+
+        let mut remaining_shift = shift.clone();
+        // A step of 256 or more would be encrypted as its low byte
+        let max_shift = max_shift.min(u8::MAX as usize);
+        let shift_bits = usize::BITS - max_shift.leading_zeros();
+
+        for bit in (0..shift_bits).rev() {
+            let step = 1usize << bit;
+            let enc_step = FheAsciiChar::encrypt_trivial(step as u8, public_parameters, &self.key);
+            let should_shift = remaining_shift.ge(&self.key, &enc_step);
+            remaining_shift = should_shift.if_then_else(
+                &self.key,
+                &remaining_shift.sub(&self.key, &enc_step),
+                &remaining_shift,
+            );
+
+            // Going backward only reads characters that have not been shifted yet
+            for k in (0..total_len).rev() {
+                let previous_char = if k >= step {
+                    bytes[k - step].clone()
+                } else {
+                    zero.clone()
+                };
+                bytes[k] = should_shift.if_then_else(&self.key, &previous_char, &bytes[k]);
+            }
+        }
+
+        bytes
+    }
+
+    /// Pads a given `FheString` with leading zeros up to a width, keeping a sign in front.
+    ///
+    /// Same as Python's `str.zfill`, `"-5"` with a width of 4 gives `"-005"`. If the string
+    /// starts with `+` or `-` the sign stays at the start and the zeros are inserted after it,
+    /// and a string that is already at least `width` long is returned as is. The content is
+    /// shifted right by the encrypted number of zeros in O(n log n).
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to pad.
+    /// * `width`: usize - The minimum length of the result, at most 255.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The zero padded string, with a capacity of at least `width`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "-5";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.zfill_signed(&my_string, 4, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "-005");
+    /// ```
+    pub fn zfill_signed(
+        &self,
+        string: &FheString,
+        width: usize,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("zfill_signed");
+        assert!(width <= u8::MAX as usize, "The width must fit in a byte");
+
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let digit_0 = FheAsciiChar::encrypt_trivial(b'0', public_parameters, &self.key);
+        let plus = FheAsciiChar::encrypt_trivial(b'+', public_parameters, &self.key);
+        let minus = FheAsciiChar::encrypt_trivial(b'-', public_parameters, &self.key);
+        let enc_width = FheAsciiChar::encrypt_trivial(width as u8, public_parameters, &self.key);
+
+        let len = self.len(string, public_parameters);
+        let fill_len = len.lt(&self.key, &enc_width).if_then_else(
+            &self.key,
+            &enc_width.sub(&self.key, &len),
+            &zero,
+        );
+
+        let mut bytes = string.get_bytes();
+        bytes.resize(usize::max(string.len(), width), zero.clone());
+
+        let (has_sign, sign) = match bytes.first() {
+            Some(first_char) => {
+                let has_sign = first_char
+                    .eq(&self.key, &plus)
+                    .bitor(&self.key, &first_char.eq(&self.key, &minus));
+                let sign = has_sign.if_then_else(&self.key, first_char, &zero);
+                (has_sign, sign)
+            }
+            None => (zero.clone(), zero.clone()),
+        };
+
+        // The zeros go to [has_sign, has_sign + fill_len), which is where the shifted sign or
+        // the start of the content lands, the sign itself is put back at the start
+        let shifted = self.shift_right(bytes, &fill_len, width, public_parameters);
+        let fill_end = fill_len.add(&self.key, &has_sign);
+
+        let result = shifted
+            .iter()
+            .enumerate()
+            .map(|(i, shifted_char)| {
+                // The fill ends before the width, which fits in a byte
+                let Ok(clear_i) = u8::try_from(i) else {
+                    return shifted_char.clone();
+                };
+
+                let enc_i = FheAsciiChar::encrypt_trivial(clear_i, public_parameters, &self.key);
+                let is_fill = enc_i
+                    .ge(&self.key, &has_sign)
+                    .bitand(&self.key, &enc_i.lt(&self.key, &fill_end));
+                let padded_char = is_fill.if_then_else(&self.key, &digit_0, shifted_char);
+
+                if i == 0 {
+                    has_sign.if_then_else(&self.key, &sign, &padded_char)
+                } else {
+                    padded_char
+                }
+            })
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::from_vec(result, public_parameters, &self.key)
+    }
+
+    /// Merges two `FheString`s character by character.
+    ///
+    /// The result is `string[0] other[0] string[1] other[1] ...`, and once the shorter of the two
+    /// runs out the rest of the longer one is appended, so `interleave("abcd", "x")` is `"axbcd"`.
+    /// The capacities are interleaved as they are, padding included, and the padding is then
+    /// moved to the end, which keeps the remainder of the longer string in order. This costs one
+    /// `from_vec_normalized`, O(n^2) in the sum of the capacities.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string providing the characters at the even positions.
+    /// * `other`: &FheString - The string providing the characters at the odd positions.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The interleaved string.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string1 = my_client_key.encrypt(
+    ///     "ace",
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let my_string2 = my_client_key.encrypt(
+    ///     "bdf",
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.interleave(&my_string1, &my_string2, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "abcdef");
+    /// ```
+    pub fn interleave(
+        &self,
+        string: &FheString,
+        other: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("interleave");
+        let string_bytes = string.get_bytes();
+        let other_bytes = other.get_bytes();
+        let mut result = Vec::with_capacity(string.len() + other.len());
+
+        for i in 0..usize::max(string.len(), other.len()) {
+            result.extend(string_bytes.get(i).cloned());
+            result.extend(other_bytes.get(i).cloned());
+        }
+
+        FheString::from_vec_normalized(result, public_parameters, &self.key)
+    }
+
+    /// Splits a given `FheString` into its characters at even and at odd positions.
+    ///
+    /// The inverse of `interleave` for two strings of the same length. The positions are public
+    /// and the content of the string is a prefix, so taking every other character keeps both
+    /// halves in the canonical layout and no encrypted operation is needed.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to split.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `(FheString, FheString)` - The characters at the even positions and the characters at the
+    /// odd positions.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abcdef";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let (even, odd) = my_server_key.deinterleave(&my_string, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt(even), "ace");
+    /// assert_eq!(my_client_key.decrypt(odd), "bdf");
+    /// ```
+    pub fn deinterleave(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> (FheString, FheString) {
+        time_method!("deinterleave");
+        let even = string.iter().step_by(2).cloned().collect();
+        let odd = string.iter().skip(1).step_by(2).cloned().collect();
+
+        (
+            FheString::from_vec(even, public_parameters, &self.key),
+            FheString::from_vec(odd, public_parameters, &self.key),
+        )
+    }
+
+    /// Rotates the characters of a given `FheString` to the left by an encrypted amount.
+    ///
+    /// Same as `s[k..] + s[..k]` with `k` taken modulo the length of the string, so rotating by
+    /// the length or more wraps around. The padding stays at the end. `k` is reduced with one
+    /// comparison per bit, then every position selects its character with one comparison per
+    /// position, which costs O(n^2) comparisons for a string of n characters.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to rotate.
+    /// * `k`: &FheAsciiChar - The encrypted number of positions to rotate by.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The rotated string.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abcde";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let k = my_client_key.encrypt_char(2u8);
+    ///
+    /// let res = my_server_key.rotate_left(&my_string, &k, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "cdeab");
+    /// ```
+    pub fn rotate_left(
+        &self,
+        string: &FheString,
+        k: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("rotate_left");
+        let len = self.len(string, public_parameters);
+        let shift = self.rem_len(k, &len, public_parameters);
+
+        self.rotate_left_by(string, &shift, &len, public_parameters)
+    }
+
+    /// Rotates the characters of a given `FheString` to the right by an encrypted amount.
+    ///
+    /// Same as `rotate_left` in the other direction, `s[len - k..] + s[..len - k]`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "abcde";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let k = my_client_key.encrypt_char(2u8);
+    ///
+    /// let res = my_server_key.rotate_right(&my_string, &k, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "deabc");
+    /// ```
+    pub fn rotate_right(
+        &self,
+        string: &FheString,
+        k: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("rotate_right");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let len = self.len(string, public_parameters);
+        let shift = self.rem_len(k, &len, public_parameters);
+
+        // Rotating right by k is rotating left by len - k, which has to stay below len
+        let is_zero = shift.eq(&self.key, &zero);
+        let shift = is_zero.if_then_else(&self.key, &zero, &len.sub(&self.key, &shift));
+
+        self.rotate_left_by(string, &shift, &len, public_parameters)
+    }
+
+    // Returns k modulo len, or k if len is 0. Long division one bit at a time, where a multiple
+    // of len that does not fit in a byte is skipped since it is larger than k anyway
+    fn rem_len(
+        &self,
+        k: &FheAsciiChar,
+        len: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheAsciiChar {
+        let mut multiples = vec![len.clone()];
+        for bit in 1..u8::BITS as usize {
+            let previous = &multiples[bit - 1];
+            multiples.push(previous.add(&self.key, previous));
+        }
+
+        let mut result = k.clone();
+        for (bit, multiple) in multiples.iter().enumerate().rev() {
+            let max_len =
+                FheAsciiChar::encrypt_trivial(u8::MAX >> bit, public_parameters, &self.key);
+            let should_sub = len
+                .le(&self.key, &max_len)
+                .bitand(&self.key, &result.ge(&self.key, multiple));
+            result = should_sub.if_then_else(&self.key, &result.sub(&self.key, multiple), &result);
+        }
+
+        result
+    }
+
+    // Rotates the content of the string left by shift, which must be less than len
+    fn rotate_left_by(
+        &self,
+        string: &FheString,
+        shift: &FheAsciiChar,
+        len: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        // An encrypted length can't reach past 255, the positions after it are padding
+        let indices = (0..=u8::MAX)
+            .take(string.len())
+            .map(|i| FheAsciiChar::encrypt_trivial(i, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        // The first len - shift positions read from shift on, the others wrap around to the start
+        let wrap_at = len.sub(&self.key, shift);
+
+        let mut bytes = indices
+            .iter()
+            .map(|enc_i| {
+                let source = enc_i.lt(&self.key, &wrap_at).if_then_else(
+                    &self.key,
+                    &enc_i.add(&self.key, shift),
+                    &enc_i.sub(&self.key, &wrap_at),
+                );
+
+                let branches = indices
+                    .iter()
+                    .zip(string.iter())
+                    .map(|(enc_j, string_char)| (source.eq(&self.key, enc_j), string_char.clone()))
+                    .collect::<Vec<(FheAsciiChar, FheAsciiChar)>>();
+                let rotated_char = FheAsciiChar::select(&self.key, &branches, &zero);
+
+                // The padding stays in place
+                enc_i
+                    .lt(&self.key, len)
+                    .if_then_else(&self.key, &rotated_char, &zero)
+            })
+            .collect::<Vec<FheAsciiChar>>();
+        bytes.resize(string.len(), zero);
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
     }
 }
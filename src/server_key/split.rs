@@ -1,17 +1,29 @@
 use crate::ciphertext::fheasciichar::FheAsciiChar;
 use crate::ciphertext::fhesplit::FheSplit;
 use crate::ciphertext::fhestring::FheString;
+use crate::ciphertext::fhestrip::FheStrip;
 use crate::ciphertext::public_parameters::PublicParameters;
-use crate::utils;
+use crate::metrics::time_method;
+use crate::MAX_FIND_LENGTH;
+use rayon::prelude::*;
 
 use super::MyServerKey;
 
+// How many parts the split helpers split the string into
+enum SplitCount {
+    All,
+    AtMost(FheAsciiChar),
+    // Known in clear, so the number of buffers and where to stop are known in advance
+    ClearAtMost(usize),
+}
+
 impl MyServerKey {
     fn rsplit_pattern_matching(
         &self,
         i: usize,
         string: &FheString,
-        pattern: &Vec<FheAsciiChar>,
+        pattern_flags: &[FheAsciiChar],
+        pattern: &[FheAsciiChar],
         ignore_pattern_mask: &mut [FheAsciiChar],
         zero: &FheAsciiChar,
         one: &FheAsciiChar,
@@ -47,13 +59,13 @@ impl MyServerKey {
         else if pattern.len() > string.len() || i + pattern.len() >= string.len() {
             pattern_found = zero.clone();
         }
-        // Actually search for pattern
+        // The raw match is only valid if it does not overlap a previous one
         else {
-            for (j, pattern_char) in pattern.iter().enumerate() {
-                let eql = string[i + j].eq(&self.key, pattern_char);
-                pattern_found = pattern_found.bitand(&self.key, &eql);
-                pattern_found = pattern_found.bitand(&self.key, &ignore_pattern_mask[i + j]);
-            }
+            pattern_found = ignore_pattern_mask[i..i + pattern.len()]
+                .iter()
+                .fold(pattern_flags[i].clone(), |pattern_found, is_not_ignored| {
+                    pattern_found.bitand(&self.key, is_not_ignored)
+                });
         }
 
         // Where this pattern matched in the string we are not allowed to match again
@@ -70,30 +82,32 @@ impl MyServerKey {
     fn split_pattern_matching(
         &self,
         i: usize,
-        string: &FheString,
-        pattern: &Vec<FheAsciiChar>,
+        pattern_flags: &[FheAsciiChar],
+        pattern: &[FheAsciiChar],
         ignore_pattern_mask: &mut [FheAsciiChar],
         zero: &FheAsciiChar,
         one: &FheAsciiChar,
     ) -> FheAsciiChar {
-        let max_buffer_size = string.len(); // when a single buffer holds the whole input
-
-        let mut pattern_found = one.clone();
-        // If pattern is larger than the string or
-        // if searching for the pattern would cause underflow then
-        // assume pattern is not found
-        if pattern.len() > string.len() || (i as i64) < (pattern.len() as i64) - 1 {
-            pattern_found = zero.clone();
-        }
-        // Actually search for pattern
-        else {
-            for (j, pattern_char) in pattern.iter().enumerate() {
-                let string_index = i - pattern.len() + 1 + j;
-                let eql = string[string_index].eq(&self.key, pattern_char);
-                pattern_found = pattern_found.bitand(&self.key, &eql);
-                pattern_found = pattern_found.bitand(&self.key, &ignore_pattern_mask[string_index]);
-            }
-        }
+        // When a single buffer holds the whole input
+        let max_buffer_size = ignore_pattern_mask.len();
+
+        // The pattern would have to start here to end at position i
+        let pattern_start = (i + 1).checked_sub(pattern.len());
+
+        // If pattern is larger than the string or if searching for the pattern would cause
+        // underflow then there is no flag and the pattern is not found
+        let pattern_found = match pattern_start
+            .and_then(|start| pattern_flags.get(start).map(|is_match| (start, is_match)))
+        {
+            // The raw match is only valid if it does not overlap a previous one
+            Some((pattern_start, is_match)) => ignore_pattern_mask
+                [pattern_start..pattern_start + pattern.len()]
+                .iter()
+                .fold(is_match.clone(), |pattern_found, is_not_ignored| {
+                    pattern_found.bitand(&self.key, is_not_ignored)
+                }),
+            None => zero.clone(),
+        };
 
         // Where this pattern matched in the string we are not allowed to match again
         for j in 0..pattern.len() {
@@ -111,7 +125,7 @@ impl MyServerKey {
         n: &Option<FheAsciiChar>,
         string: &FheString,
         result: &mut [Vec<FheAsciiChar>],
-        public_parameters: &PublicParameters,
+        buffer_indices: &[FheAsciiChar],
         flags: (&FheAsciiChar, &FheAsciiChar),
     ) {
         let allow_copying = &flags.0;
@@ -121,8 +135,11 @@ impl MyServerKey {
         let max_no_buffers = max_buffer_size; // when all buffers hold an empty value
 
         // Copy ith character to the appropriate buffer
-        for (j, result_item) in result.iter_mut().enumerate().take(max_no_buffers) {
-            let enc_j = FheAsciiChar::encrypt_trivial(j as u8, public_parameters, &self.key);
+        for (enc_j, result_item) in buffer_indices
+            .iter()
+            .zip(result.iter_mut())
+            .take(max_no_buffers)
+        {
             let mut copy_flag = enc_j.eq(&self.key, current_copy_buffer);
 
             // Edge case, if n = 0 we never copy anything
@@ -177,6 +194,43 @@ impl MyServerKey {
         };
     }
 
+    // Same as the `Some(n)` case of `clear_pattern_from_result` for a plaintext n, the last buffer
+    // keeps the remaining delimiters so it is only normalized and the replace is skipped for it
+    fn clear_pattern_from_clear_n_result(
+        &self,
+        clear_n: usize,
+        result: &mut [Vec<FheAsciiChar>],
+        pattern: &Vec<FheAsciiChar>,
+        public_parameters: &PublicParameters,
+    ) {
+        let to =
+            vec![FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key); pattern.len()];
+
+        result
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(i, result_buffer)| {
+                let max_buffer_size = result_buffer.len();
+                let current_string = FheString::from_vec_normalized(
+                    result_buffer.clone(),
+                    public_parameters,
+                    &self.key,
+                );
+
+                let cleared_string = if i + 1 < clear_n {
+                    self.replace(&current_string, pattern, &to, public_parameters)
+                } else {
+                    current_string
+                };
+
+                *result_buffer = cleared_string
+                    .get_bytes()
+                    .into_iter()
+                    .take(max_buffer_size)
+                    .collect();
+            });
+    }
+
     fn clear_pattern_from_result(
         &self,
         n: &Option<FheAsciiChar>,
@@ -192,8 +246,8 @@ impl MyServerKey {
         let is_inclusive = flags.0;
         let is_terminator = flags.1;
 
-        let max_buffer_size = result.len(); // when a single buffer holds the whole input
-        let max_no_buffers = max_buffer_size; // when all buffers hold an empty value
+        let max_no_buffers = result.len();
+        let max_buffer_size = result.first().map_or(0, |buffer| buffer.len());
 
         match &n {
             Some(max_splits) => {
@@ -205,32 +259,43 @@ impl MyServerKey {
                     .collect();
                 let mut stop_replacing_pattern = zero.clone();
 
-                for (i, result_buffer) in result.iter_mut().enumerate().take(max_no_buffers) {
-                    let enc_i =
-                        FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
-                    stop_replacing_pattern = stop_replacing_pattern.bitor(
-                        &self.key,
-                        &max_splits.eq(&self.key, &enc_i.add(&self.key, one)),
-                    );
-
-                    let current_string =
-                        FheString::from_vec(result_buffer.clone(), public_parameters, &self.key);
-                    let current_string =
-                        utils::bubble_zeroes_right(current_string, &self.key, public_parameters);
-                    let replacement_string =
-                        self.replace(&current_string, pattern, &to, public_parameters);
-
-                    // Don't remove pattern from (n-1)th buffer
-                    for (j, result_buffer_char) in
-                        result_buffer.iter_mut().enumerate().take(max_buffer_size)
-                    {
-                        *result_buffer_char = stop_replacing_pattern.if_then_else(
+                // The flag only depends on the buffer index, compute it for every buffer first so
+                // that the buffers can then be processed independently
+                let stop_replacing_flags = (0..max_no_buffers)
+                    .map(|i| {
+                        let enc_i =
+                            FheAsciiChar::encrypt_trivial(i as u8, public_parameters, &self.key);
+                        stop_replacing_pattern = stop_replacing_pattern.bitor(
                             &self.key,
-                            &current_string[j],
-                            &replacement_string[j],
+                            &max_splits.eq(&self.key, &enc_i.add(&self.key, one)),
                         );
-                    }
-                }
+                        stop_replacing_pattern.clone()
+                    })
+                    .collect::<Vec<FheAsciiChar>>();
+
+                result
+                    .par_iter_mut()
+                    .zip(stop_replacing_flags.par_iter())
+                    .for_each(|(result_buffer, stop_replacing_pattern)| {
+                        let current_string = FheString::from_vec_normalized(
+                            result_buffer.clone(),
+                            public_parameters,
+                            &self.key,
+                        );
+                        let replacement_string =
+                            self.replace(&current_string, pattern, &to, public_parameters);
+
+                        // Don't remove pattern from (n-1)th buffer
+                        for (j, result_buffer_char) in
+                            result_buffer.iter_mut().enumerate().take(max_buffer_size)
+                        {
+                            *result_buffer_char = stop_replacing_pattern.if_then_else(
+                                &self.key,
+                                &current_string[j],
+                                &replacement_string[j],
+                            );
+                        }
+                    });
             }
             None => {
                 if !is_inclusive {
@@ -242,8 +307,8 @@ impl MyServerKey {
                         .collect();
 
                     // Since the pattern is also copied at the end of each buffer go through them
-                    // and delete it
-                    for result_buffer in result.iter_mut().take(max_no_buffers) {
+                    // and delete it, the buffers are independent so do it in parallel
+                    result.par_iter_mut().for_each(|result_buffer| {
                         let current_string = FheString::from_vec(
                             result_buffer.clone(),
                             public_parameters,
@@ -252,23 +317,20 @@ impl MyServerKey {
                         let replacement_string =
                             self.replace(&current_string, pattern, &to, public_parameters);
                         *result_buffer = replacement_string.get_bytes();
-                    }
+                    });
                 } else {
-                    for result_buffer in result.iter_mut().take(max_no_buffers) {
-                        let new_buf = utils::bubble_zeroes_right(
-                            FheString::from_vec(
-                                result_buffer.clone(),
-                                public_parameters,
-                                &self.key,
-                            ),
-                            &self.key,
+                    result.par_iter_mut().for_each(|result_buffer| {
+                        let new_buf = FheString::from_vec_normalized(
+                            result_buffer.clone(),
                             public_parameters,
+                            &self.key,
                         );
                         *result_buffer = new_buf.get_bytes();
-                    }
+                    });
                 }
 
-                // Zero out the last populated buffer if it starts with the pattern
+                // Zero out the last populated buffer if it starts with the pattern, this scans the
+                // buffers in reverse with a carried flag so it stays sequential
                 if is_terminator {
                     let mut non_zero_buffer_found = zero.clone();
                     for i in (0..max_no_buffers).rev() {
@@ -306,7 +368,7 @@ impl MyServerKey {
 
     fn _rsplit(
         &self,
-        mut string: FheString,
+        string: FheString,
         pattern: Vec<FheAsciiChar>,
         is_inclusive: bool,
         is_terminator: bool,
@@ -317,8 +379,7 @@ impl MyServerKey {
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
         let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
 
-        // Pad the string to avoid edge cases
-        string.push(zero.clone());
+        let string = string.terminated(public_parameters, &self.key);
 
         let max_buffer_size = string.len(); // when a single buffer holds the whole input
         let max_no_buffers = max_buffer_size; // when all buffers hold an empty value
@@ -328,6 +389,11 @@ impl MyServerKey {
         let mut result = vec![vec![zero.clone(); max_buffer_size]; max_no_buffers];
         let mut global_pattern_found = zero.clone();
 
+        // Buffer indices are compared against for every character, encrypt them only once
+        let buffer_indices = (0..max_no_buffers)
+            .map(|j| FheAsciiChar::encrypt_trivial(j as u8, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
         // Edge case flag, if n = 0 we ever copy anything
         let mut allow_copying = zero.clone();
         // Mask that stops overlapping patterns to match
@@ -335,6 +401,9 @@ impl MyServerKey {
         // without this flag the pattern would match more times than it should
         let mut ignore_pattern_mask = vec![one.clone(); max_buffer_size];
 
+        // The raw matches at every offset, before the overlapping ones are masked
+        let pattern_flags = self.pattern_match_flags(&string, &pattern, public_parameters);
+
         // Edge case, if n = 0 we never copy anything
         if n.is_some() {
             let n_value = n.clone().unwrap();
@@ -349,7 +418,7 @@ impl MyServerKey {
                 &n,
                 &string,
                 &mut result,
-                public_parameters,
+                &buffer_indices,
                 (&allow_copying, &current_copy_buffer),
             );
 
@@ -357,6 +426,7 @@ impl MyServerKey {
             let pattern_found = self.rsplit_pattern_matching(
                 i,
                 &string,
+                &pattern_flags,
                 &pattern,
                 &mut ignore_pattern_mask,
                 &zero,
@@ -394,6 +464,10 @@ impl MyServerKey {
 
     /// Splits a given `FheString` into multiple parts from the right, based on a specified pattern.
     ///
+    /// The parts are returned in the order `str::rsplit` yields them, starting from the end of
+    /// the string, followed by empty buffers. An empty pattern matches before and after every
+    /// character, so `"abc"` is split into `["", "c", "b", "a", ""]`.
+    ///
     /// # Arguments
     /// * `string`: &FheString - The string to be split.
     /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to split on.
@@ -442,6 +516,7 @@ impl MyServerKey {
         pattern: &[FheAsciiChar],
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("rsplit");
         self._rsplit(
             string.clone(),
             pattern.to_owned(),
@@ -495,6 +570,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("rsplit_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -505,6 +581,9 @@ impl MyServerKey {
     /// Splits a given `FheString` into a limited number of parts from the right, based on
     /// a specified pattern.
     ///
+    /// The parts are returned in the order `str::rsplitn` yields them, starting from the end of
+    /// the string, followed by empty buffers.
+    ///
     /// # Arguments
     /// * `string`: &FheString - The string to be split.
     /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to split on.
@@ -557,6 +636,7 @@ impl MyServerKey {
         n: FheAsciiChar,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("rsplitn");
         self._rsplit(
             string.clone(),
             pattern.to_owned(),
@@ -618,6 +698,7 @@ impl MyServerKey {
         clear_n: usize,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("rsplitn_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -636,6 +717,15 @@ impl MyServerKey {
     /// Splits a given `FheString` into two parts from the right, based on a specified
     /// pattern.
     ///
+    /// The parts are returned in the order `str::rsplitn(2, pattern)` yields them, so this is the
+    /// reverse of the `(before, after)` tuple of `str::rsplit_once`: the first buffer holds the
+    /// part after the pattern and the second buffer the part before it.
+    ///
+    /// Where `str::rsplit_once` returns `None` because the pattern does not occur, the
+    /// `pattern_found` flag of the result is an encrypted 0. The first buffer then holds the whole
+    /// string and all the other buffers are empty, so the client has to check the flag to tell
+    /// this case apart from a split with an empty part.
+    ///
     /// # Arguments
     /// * `string`: &FheString - The string to be split.
     /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to split on.
@@ -643,7 +733,7 @@ impl MyServerKey {
     ///
     /// # Returns
     /// `FheSplit` - A struct containing the split parts of the string and a boolean flag
-    /// indicating whether a split was made.
+    /// indicating whether a split was made, i.e. 0 where `str::rsplit_once` returns `None`.
     ///
     /// # Example:
     /// ```
@@ -684,6 +774,7 @@ impl MyServerKey {
         pattern: &[FheAsciiChar],
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("rsplit_once");
         let n = FheAsciiChar::encrypt_trivial(2u8, public_parameters, &self.key);
         self._rsplit(
             string.clone(),
@@ -698,7 +789,8 @@ impl MyServerKey {
     /// Splits a given `FheString` into two parts from the right, based on a specified plaintext
     /// pattern.
     ///
-    /// Same as `rsplit_once` but with a plaintext pattern.
+    /// Same as `rsplit_once` but with a plaintext pattern. The `None` case is encoded the same way,
+    /// with the `pattern_found` flag of the result set to 0.
     ///
     /// # Example:
     /// ```
@@ -739,6 +831,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("rsplit_once_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -757,6 +850,9 @@ impl MyServerKey {
     /// Splits a given `FheString` into multiple parts from the right, based on a specified pattern,
     /// excluding the trailing empty string if any.
     ///
+    /// The parts are returned in the order `str::rsplit_terminator` yields them, starting from the
+    /// end of the string, followed by empty buffers.
+    ///
     /// # Arguments
     /// * `string`: &FheString - The string to be split.
     /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to split on.
@@ -809,6 +905,7 @@ impl MyServerKey {
         pattern: &[FheAsciiChar],
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("rsplit_terminator");
         self._rsplit(
             string.clone(),
             pattern.to_owned(),
@@ -866,6 +963,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("rsplit_terminator_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -882,28 +980,72 @@ impl MyServerKey {
 
     fn _split(
         &self,
-        mut string: FheString,
+        string: FheString,
         pattern: Vec<FheAsciiChar>,
         is_inclusive: bool,
         is_terminator: bool,
         n: Option<FheAsciiChar>,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        let (fhe_split, _) = self._split_tracking_positions(
+            string,
+            pattern,
+            (is_inclusive, is_terminator, false),
+            n.map_or(SplitCount::All, SplitCount::AtMost),
+            public_parameters,
+        );
+
+        fhe_split
+    }
+
+    // Same as `_split`, if `track_positions` is set it also returns the start position of every
+    // delimiter, otherwise the returned positions are empty. With a plaintext count only that
+    // many buffers are allocated.
+    fn _split_tracking_positions(
+        &self,
+        string: FheString,
+        pattern: Vec<FheAsciiChar>,
+        flags: (bool, bool, bool),
+        count: SplitCount,
+        public_parameters: &PublicParameters,
+    ) -> (FheSplit, Vec<FheAsciiChar>) {
+        let is_inclusive = flags.0;
+        let is_terminator = flags.1;
+        let track_positions = flags.2;
+
         // Compute constants
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
         let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
 
-        // Pad the string to avoid edge cases
-        string.push(zero.clone());
+        let string = string.terminated(public_parameters, &self.key);
 
         let max_buffer_size = string.len(); // when a single buffer holds the whole input
-        let max_no_buffers = max_buffer_size; // when all buffers hold an empty value
+
+        // When all buffers hold an empty value, unless the count is known in clear
+        let max_no_buffers = match count {
+            SplitCount::ClearAtMost(clear_n) => usize::min(clear_n, max_buffer_size),
+            _ => max_buffer_size,
+        };
+
+        // The shared helpers only know about the encrypted count
+        let n = match &count {
+            SplitCount::AtMost(n) => Some(n.clone()),
+            _ => None,
+        };
 
         let mut current_copy_buffer = zero.clone();
         let mut stop_counter_increment = zero.clone();
         let mut result = vec![vec![zero.clone(); max_buffer_size]; max_no_buffers];
         let mut global_pattern_found = zero.clone();
 
+        // Buffer indices are compared against for every character, encrypt them only once
+        let buffer_indices = (0..max_no_buffers)
+            .map(|j| FheAsciiChar::encrypt_trivial(j as u8, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        // With a plaintext count the split stops at the last allocated buffer
+        let last_buffer_index = max_no_buffers.saturating_sub(1) as u8;
+
         // Edge case flag, if n = 0 we ever copy anything
         let mut allow_copying = zero.clone();
 
@@ -912,29 +1054,50 @@ impl MyServerKey {
         // without this flag the pattern would match more times than it should
         let mut ignore_pattern_mask = vec![one.clone(); max_buffer_size];
 
+        // The raw matches at every offset, before the overlapping ones are masked
+        let pattern_flags = self.pattern_match_flags(&string, &pattern, public_parameters);
+
+        // The kth entry holds the start of the kth delimiter, or MAX_FIND_LENGTH
+        let not_found =
+            FheAsciiChar::encrypt_trivial(MAX_FIND_LENGTH as u8, public_parameters, &self.key);
+        let mut delimiter_positions = if track_positions {
+            vec![not_found; max_no_buffers]
+        } else {
+            vec![]
+        };
+
         // Edge case, if n = 0 we ever copy anything
         if n.is_some() {
             let n_value = n.clone().unwrap();
             allow_copying = n_value.ne(&self.key, &zero);
         }
 
-        // Handle edge case when 1 < n <= string.len() and pattern is empty
+        // Handle edge case when 1 < n and pattern is empty
         // In this case we should leave an empty buffer effectively skipping the first one
         // Example1:  "eeeeee".splitn(2, "") --> ["", "eeeeee"]
         // Example2:  "eeeeee".splitn(3, "") --> ["", "e", "eeeee"]
-        if pattern.is_empty() && n.is_some() {
-            let n_value = n.clone().unwrap();
-            let enc_len = self.len(&string, public_parameters);
-
-            let should_skip_first_buffer = n_value
-                .gt(&self.key, &one)
-                .bitand(&self.key, &n_value.le(&self.key, &enc_len));
+        // Example3:  "ee".splitn(10, "") --> ["", "e", "e", ""]
+        // Same for a plain split, the empty pattern also matches before the first character
+        // Example:  "abc".split("") --> ["", "a", "b", "c", ""]
+        if pattern.is_empty() {
+            match &count {
+                SplitCount::AtMost(n_value) => {
+                    let should_skip_first_buffer = n_value.gt(&self.key, &one);
 
-            current_copy_buffer = should_skip_first_buffer.if_then_else(
-                &self.key,
-                &FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key),
-                &current_copy_buffer,
-            );
+                    current_copy_buffer = should_skip_first_buffer.if_then_else(
+                        &self.key,
+                        &FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key),
+                        &current_copy_buffer,
+                    );
+                }
+                SplitCount::ClearAtMost(clear_n) if *clear_n > 1 => {
+                    current_copy_buffer = one.clone();
+                }
+                SplitCount::All if !is_inclusive && !is_terminator => {
+                    current_copy_buffer = one.clone();
+                }
+                _ => {}
+            }
         }
 
         for i in 0..(string.len()) {
@@ -945,13 +1108,13 @@ impl MyServerKey {
                 &n,
                 &string,
                 &mut result,
-                public_parameters,
+                &buffer_indices,
                 (&allow_copying, &current_copy_buffer),
             );
 
             let pattern_found = self.split_pattern_matching(
                 i,
-                &string,
+                &pattern_flags,
                 &pattern,
                 &mut ignore_pattern_mask,
                 &zero,
@@ -960,35 +1123,85 @@ impl MyServerKey {
 
             global_pattern_found = global_pattern_found.bitor(&self.key, &pattern_found);
 
+            // The pattern ends at i, and the number of delimiters found before it is the index
+            // of the buffer we are copying to, an empty pattern has no delimiter to locate
+            let pattern_start = (i + 1)
+                .checked_sub(pattern.len())
+                .filter(|_| !pattern.is_empty());
+
+            if let Some(pattern_start) = pattern_start {
+                let enc_pattern_start = FheAsciiChar::encrypt_trivial(
+                    pattern_start as u8,
+                    public_parameters,
+                    &self.key,
+                );
+
+                for (enc_k, position) in buffer_indices.iter().zip(delimiter_positions.iter_mut()) {
+                    let is_kth_delimiter =
+                        pattern_found.bitand(&self.key, &enc_k.eq(&self.key, &current_copy_buffer));
+                    *position =
+                        is_kth_delimiter.if_then_else(&self.key, &enc_pattern_start, position);
+                }
+            }
+
             // Ignore pattern founds if we reached the apropriate number of splits in
             // the n case
-            self.handle_n_case(
-                &pattern_found,
-                &n,
-                &mut current_copy_buffer,
-                &mut stop_counter_increment,
-                &one,
-                public_parameters,
-            );
+            if let SplitCount::ClearAtMost(_) = count {
+                // The buffer index only grows, so once it reaches the last buffer it stays there
+                // and comparing it against the plaintext index replaces the stop flag
+                let should_switch_buffer = pattern_found.bitand(
+                    &self.key,
+                    &current_copy_buffer.scalar_ne(&self.key, last_buffer_index),
+                );
+                current_copy_buffer = should_switch_buffer.if_then_else(
+                    &self.key,
+                    &current_copy_buffer.add(&self.key, &one),
+                    &current_copy_buffer,
+                );
+            } else {
+                self.handle_n_case(
+                    &pattern_found,
+                    &n,
+                    &mut current_copy_buffer,
+                    &mut stop_counter_increment,
+                    &one,
+                    public_parameters,
+                );
+            }
         }
 
         // After we are done with copying, we delete the pattern from the copy buffers
         // depending on the rsplit flavour and move all non \0 chars to the start of the string
         // This is the slowest part of the process
-        self.clear_pattern_from_result(
-            &n,
-            &mut result,
-            &pattern,
-            public_parameters,
-            (&zero, &one),
-            (is_inclusive, is_terminator),
-        );
+        if let SplitCount::ClearAtMost(clear_n) = count {
+            self.clear_pattern_from_clear_n_result(
+                clear_n,
+                &mut result,
+                &pattern,
+                public_parameters,
+            );
+        } else {
+            self.clear_pattern_from_result(
+                &n,
+                &mut result,
+                &pattern,
+                public_parameters,
+                (&zero, &one),
+                (is_inclusive, is_terminator),
+            );
+        }
 
-        FheSplit::new(result, global_pattern_found, public_parameters, &self.key)
+        (
+            FheSplit::new(result, global_pattern_found, public_parameters, &self.key),
+            delimiter_positions,
+        )
     }
 
     /// Splits a given `FheString` into multiple parts based on a specified pattern.
     ///
+    /// An empty pattern matches before and after every character like in `str::split`, so
+    /// `"abc"` is split into `["", "a", "b", "c", ""]` followed by empty buffers.
+    ///
     /// # Arguments
     /// * `string`: &FheString - The string to be split.
     /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to split on.
@@ -1041,6 +1254,7 @@ impl MyServerKey {
         pattern: &[FheAsciiChar],
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("split");
         self._split(
             string.clone(),
             pattern.to_owned(),
@@ -1097,6 +1311,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("split_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -1104,6 +1319,94 @@ impl MyServerKey {
         self.split(string, &pattern, public_parameters)
     }
 
+    /// Splits a given `FheString` into multiple parts based on a specified pattern, and returns
+    /// where the delimiters were found.
+    ///
+    /// Same as `split`, the kth returned position is the start of the kth occurrence of the
+    /// pattern in the original string, the remaining positions are encrypted MAX_FIND_LENGTH.
+    /// This allows to locate the parts in the original string without searching again. An empty
+    /// pattern has no delimiter to locate, so all positions are then MAX_FIND_LENGTH.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to be split.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to split on.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `(FheSplit, Vec<FheAsciiChar>)` - The same `FheSplit` as `split` and the encrypted
+    /// delimiter positions, one per buffer.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a.b.c";
+    /// let pattern_plain = ".";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+    /// let (fhe_split, positions) =
+    ///     my_server_key.split_with_positions(&my_string, &pattern, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&positions[0]), 1u8);
+    /// assert_eq!(my_client_key.decrypt_char(&positions[1]), 3u8);
+    /// assert_eq!(my_client_key.decrypt_char(&positions[2]), MAX_FIND_LENGTH as u8);
+    /// ```
+    pub fn split_with_positions(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> (FheSplit, Vec<FheAsciiChar>) {
+        time_method!("split_with_positions");
+        self._split_tracking_positions(
+            string.clone(),
+            pattern.to_owned(),
+            (false, false, true),
+            SplitCount::All,
+            public_parameters,
+        )
+    }
+
+    /// Splits a given `FheString` into multiple parts based on a specified plaintext pattern, and
+    /// returns where the delimiters were found.
+    ///
+    /// Same as `split_with_positions` but with a plaintext pattern.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a.b.c";
+    /// let pattern_plain = ".";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let (fhe_split, positions) =
+    ///     my_server_key.split_with_positions_clear(&my_string, &pattern_plain, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&positions[0]), 1u8);
+    /// assert_eq!(my_client_key.decrypt_char(&positions[1]), 3u8);
+    /// ```
+    pub fn split_with_positions_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        public_parameters: &PublicParameters,
+    ) -> (FheSplit, Vec<FheAsciiChar>) {
+        time_method!("split_with_positions_clear");
+        let pattern = clear_pattern
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+        self.split_with_positions(string, &pattern, public_parameters)
+    }
+
     /// Splits a given `FheString` into multiple parts based on a specified pattern,
     /// including the pattern in the split parts.
     ///
@@ -1158,6 +1461,7 @@ impl MyServerKey {
         pattern: &[FheAsciiChar],
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("split_inclusive");
         self._split(
             string.clone(),
             pattern.to_owned(),
@@ -1214,6 +1518,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("split_inclusive_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -1270,6 +1575,7 @@ impl MyServerKey {
         pattern: &[FheAsciiChar],
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("split_terminator");
         self._split(
             string.clone(),
             pattern.to_owned(),
@@ -1322,6 +1628,7 @@ impl MyServerKey {
         clear_pattern: &str,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("split_terminator_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
@@ -1379,6 +1686,7 @@ impl MyServerKey {
         string: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("split_ascii_whitespace");
         let max_buffer_size = string.len(); // when a single buffer holds the whole input
         let max_no_buffers = max_buffer_size; // when all buffers hold an empty value
 
@@ -1390,8 +1698,13 @@ impl MyServerKey {
             FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
         let mut global_pattern_found = zero.clone();
 
+        // Buffer indices are compared against for every character, encrypt them only once
+        let buffer_indices = (0..max_no_buffers)
+            .map(|j| FheAsciiChar::encrypt_trivial(j as u8, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
         for i in 0..(string.len()) {
-            let pattern_found = string[i].is_whitespace(&self.key, public_parameters);
+            let pattern_found = string[i].is_whitespace(&self.key);
             global_pattern_found = global_pattern_found.bitor(&self.key, &pattern_found);
 
             let should_increment_buffer = pattern_found.bitand(
@@ -1408,16 +1721,11 @@ impl MyServerKey {
                 &current_copy_buffer,
             );
 
-            // Copy ith character to the appropriate buffer
-            for (j, result_buffer) in result.iter_mut().enumerate().take(max_no_buffers) {
-                let enc_j = FheAsciiChar::encrypt_trivial(j as u8, public_parameters, &self.key);
+            // Copy ith character to the appropriate buffer, if its not whitespace
+            let is_not_whitespace = pattern_found.flip(&self.key, public_parameters);
+            for (enc_j, result_buffer) in buffer_indices.iter().zip(result.iter_mut()) {
                 let mut copy_flag = enc_j.eq(&self.key, &current_copy_buffer);
-                copy_flag = copy_flag.bitand(
-                    &self.key,
-                    &string[i]
-                        .is_whitespace(&self.key, public_parameters)
-                        .flip(&self.key, public_parameters),
-                ); // copy if its not whitespace
+                copy_flag = copy_flag.bitand(&self.key, &is_not_whitespace);
                 result_buffer[i] = copy_flag.if_then_else(&self.key, &string[i], &result_buffer[i]);
             }
 
@@ -1427,25 +1735,66 @@ impl MyServerKey {
         // Replace whitespace with \0
         for result_buffer in result.iter_mut().take(max_no_buffers) {
             for result_buffer_char in result_buffer.iter_mut().take(max_buffer_size) {
-                let replace_with_zero =
-                    result_buffer_char.is_whitespace(&self.key, public_parameters);
+                let replace_with_zero = result_buffer_char.is_whitespace(&self.key);
                 *result_buffer_char =
                     replace_with_zero.if_then_else(&self.key, &zero, result_buffer_char);
             }
         }
 
         for result_buffer in result.iter_mut().take(max_no_buffers) {
-            let new_buf = utils::bubble_zeroes_right(
-                FheString::from_vec(result_buffer.clone(), public_parameters, &self.key),
-                &self.key,
-                public_parameters,
-            );
+            let new_buf =
+                FheString::from_vec_normalized(result_buffer.clone(), public_parameters, &self.key);
             *result_buffer = new_buf.get_bytes();
         }
 
         FheSplit::new(result, global_pattern_found, public_parameters, &self.key)
     }
 
+    /// Reverses the order of the whitespace separated words of a given `FheString`.
+    ///
+    /// Same as `s.split_ascii_whitespace().rev().collect::<Vec<_>>().join(" ")`, the words are
+    /// kept intact and separated by a single space whatever the whitespace between them was.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string whose words are reversed.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The words of the string in reverse order.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "the quick  brown";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let res = my_server_key.reverse_words(&my_string, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "brown quick the");
+    /// ```
+    pub fn reverse_words(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("reverse_words");
+        let mut fhe_split = self.split_ascii_whitespace(string, public_parameters);
+        fhe_split.buffers.reverse();
+
+        // The empty buffers are now in front of the words and `rejoin` puts a separator after
+        // each of them, which leaves only leading spaces to remove
+        let space = FheAsciiChar::encrypt_trivial(b' ', public_parameters, &self.key);
+        let joined = fhe_split.rejoin(&[space], self, public_parameters);
+
+        self.trim_start(&joined, public_parameters)
+    }
+
     /// Splits a given `FheString` into a limited number of parts based on a specified pattern.
     ///
     /// # Arguments
@@ -1501,6 +1850,7 @@ impl MyServerKey {
         n: FheAsciiChar,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("splitn");
         self._split(
             string.clone(),
             pattern.to_owned(),
@@ -1514,7 +1864,10 @@ impl MyServerKey {
     /// Splits a given `FheString` into a limited number of parts based on a specified
     /// plaintext pattern and plaintext count.
     ///
-    /// Same as `splitn` but with plaintext pattern and count.
+    /// Same as `splitn` but with plaintext pattern and count. Since `n` is known only `n` buffers
+    /// are allocated instead of one per character, and the switch to the next buffer is checked
+    /// against the plaintext index of the last one. The parts are the same as the ones of
+    /// `splitn`, without its trailing empty buffers.
     ///
     /// # Example:
     /// ```
@@ -1534,20 +1887,7 @@ impl MyServerKey {
     ///
     /// assert_eq!(
     ///     plain_split,
-    ///     (
-    ///         vec![
-    ///             "".to_owned(),
-    ///             "A.B.C.".to_owned(),
-    ///             "".to_owned(),
-    ///             "".to_owned(),
-    ///             "".to_owned(),
-    ///             "".to_owned(),
-    ///             "".to_owned(),
-    ///             "".to_owned(),
-    ///             "".to_owned(),
-    ///         ],
-    ///         1u8
-    ///     )
+    ///     (vec!["".to_owned(), "A.B.C.".to_owned()], 1u8)
     /// );
     /// ```
     pub fn splitn_clear(
@@ -1557,18 +1897,276 @@ impl MyServerKey {
         clear_n: usize,
         public_parameters: &PublicParameters,
     ) -> FheSplit {
+        time_method!("splitn_clear");
         let pattern = clear_pattern
             .bytes()
             .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
             .collect::<Vec<FheAsciiChar>>();
-        let n = FheAsciiChar::encrypt_trivial(clear_n as u8, public_parameters, &self.key);
-        self._split(
+
+        let (fhe_split, _) = self._split_tracking_positions(
             string.clone(),
             pattern,
-            false,
-            false,
-            Some(n),
+            (false, false, false),
+            SplitCount::ClearAtMost(clear_n),
             public_parameters,
-        )
+        );
+
+        fhe_split
+    }
+
+    /// Splits a given `FheString` into two parts at the first occurrence of a specified pattern.
+    ///
+    /// Same as `str::split_once`: the first buffer holds the part before the pattern and the
+    /// second buffer everything after it, including the later occurrences of the pattern. Since
+    /// the count is known only two buffers are allocated, like `splitn_clear` with 2.
+    ///
+    /// Where `str::split_once` returns `None` because the pattern does not occur, the
+    /// `pattern_found` flag of the result is an encrypted 0. The first buffer then holds the whole
+    /// string and the second one is empty, so the client has to check the flag to tell this case
+    /// apart from a split with an empty part.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to be split.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to split on.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheSplit` - A struct containing the two parts of the string and a boolean flag
+    /// indicating whether a split was made, i.e. 0 where `str::split_once` returns `None`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a.b.c";
+    /// let pattern_plain = ".";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+    /// let fhe_split = my_server_key.split_once(&my_string, &pattern, &public_parameters);
+    /// let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    ///
+    /// assert_eq!(plain_split, (vec!["a".to_owned(), "b.c".to_owned()], 1u8));
+    /// ```
+    pub fn split_once(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> FheSplit {
+        time_method!("split_once");
+        let (fhe_split, _) = self._split_tracking_positions(
+            string.clone(),
+            pattern.to_owned(),
+            (false, false, false),
+            SplitCount::ClearAtMost(2),
+            public_parameters,
+        );
+
+        fhe_split
+    }
+
+    /// Splits a given `FheString` into two parts at the first occurrence of a specified plaintext
+    /// pattern.
+    ///
+    /// Same as `split_once` but with a plaintext pattern. The `None` case is encoded the same way,
+    /// with the `pattern_found` flag of the result set to 0.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a.b.c";
+    /// let pattern_plain = ".";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    ///
+    /// let fhe_split = my_server_key.split_once_clear(&my_string, &pattern_plain, &public_parameters);
+    /// let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    ///
+    /// assert_eq!(plain_split, (vec!["a".to_owned(), "b.c".to_owned()], 1u8));
+    /// ```
+    pub fn split_once_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheSplit {
+        time_method!("split_once_clear");
+        let pattern = clear_pattern
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        self.split_once(string, &pattern, public_parameters)
+    }
+
+    /// Splits a given `FheString` into at most `max_segments` parts based on a specified pattern.
+    ///
+    /// `split` allocates one buffer per character of the input, so its memory grows with the
+    /// square of the length. When the pattern is known to be rare the caller can bound the
+    /// number of buffers instead. The occurrences of the pattern after the first
+    /// `max_segments - 1` are not split on, the rest of the string is merged into the last buffer
+    /// with the delimiters kept, which is what `str::splitn(max_segments, pattern)` returns.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to be split.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to split on.
+    /// * `max_segments`: usize - The maximum number of parts, at least 1.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheSplit` - A struct containing at most `max_segments` parts and a boolean flag
+    /// indicating whether a split was made.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "a,b,c,d";
+    /// let pattern_plain = ",";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+    /// let fhe_split = my_server_key.split_bounded(&my_string, &pattern, 2, &public_parameters);
+    /// let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    ///
+    /// assert_eq!(plain_split, (vec!["a".to_owned(), "b,c,d".to_owned()], 1u8));
+    /// ```
+    pub fn split_bounded(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        max_segments: usize,
+        public_parameters: &PublicParameters,
+    ) -> FheSplit {
+        time_method!("split_bounded");
+        assert!(max_segments >= 1, "At least one segment is needed");
+
+        let (fhe_split, _) = self._split_tracking_positions(
+            string.clone(),
+            pattern.to_owned(),
+            (false, false, false),
+            SplitCount::ClearAtMost(max_segments),
+            public_parameters,
+        );
+
+        fhe_split
+    }
+
+    /// Returns the part of a given `FheString` before the first occurrence of a character, like
+    /// `s.split(delim).next()`.
+    ///
+    /// Cheaper than a full split when only the head is needed, the string is scanned once and
+    /// every character from the first delimiter on is replaced with \0. The whole string is
+    /// returned if it does not contain the delimiter.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to cut.
+    /// * `delim`: &FheAsciiChar - The delimiter character.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The characters before the first delimiter.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "key=value";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let delim = my_client_key.encrypt_char(b'=');
+    /// let head = my_server_key.truncate_at_char(&my_string, &delim, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt(head), "key");
+    /// ```
+    pub fn truncate_at_char(
+        &self,
+        string: &FheString,
+        delim: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("truncate_at_char");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut delim_found = zero.clone();
+
+        // The head stays in front, so the result is still in the canonical layout
+        let bytes = string
+            .iter()
+            .map(|string_char| {
+                delim_found = delim_found.bitor(&self.key, &string_char.eq(&self.key, delim));
+                delim_found.if_then_else(&self.key, &zero, string_char)
+            })
+            .collect::<Vec<FheAsciiChar>>();
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Returns the part of a given `FheString` after the first occurrence of a character, like
+    /// `s.split_once(delim).map(|(_, tail)| tail)`.
+    ///
+    /// The complement of `truncate_at_char`. Every character up to the first delimiter is
+    /// replaced with \0 and the tail is then moved to the front.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to cut.
+    /// * `delim`: &FheAsciiChar - The delimiter character.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheStrip` - A struct containing the characters after the first delimiter, empty if there
+    /// is none, and a boolean flag indicating whether the delimiter was found.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "key=value";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let delim = my_client_key.encrypt_char(b'=');
+    /// let fhe_strip = my_server_key.after_char(&my_string, &delim, &public_parameters);
+    ///
+    /// assert_eq!(FheStrip::decrypt(fhe_strip, &my_client_key), ("value".to_owned(), 1u8));
+    /// ```
+    pub fn after_char(
+        &self,
+        string: &FheString,
+        delim: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheStrip {
+        time_method!("after_char");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut delim_found = zero.clone();
+
+        // A character is kept if a delimiter was found before it, so nothing is kept if there is
+        // no delimiter at all
+        let bytes = string
+            .iter()
+            .map(|string_char| {
+                let kept_char = delim_found.if_then_else(&self.key, string_char, &zero);
+                delim_found = delim_found.bitor(&self.key, &string_char.eq(&self.key, delim));
+                kept_char
+            })
+            .collect::<Vec<FheAsciiChar>>();
+
+        let tail = FheString::from_vec_normalized(bytes, public_parameters, &self.key);
+        FheStrip::new(tail, delim_found)
     }
 }
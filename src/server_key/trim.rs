@@ -1,6 +1,7 @@
 use crate::ciphertext::fheasciichar::FheAsciiChar;
 use crate::ciphertext::fhestring::FheString;
 use crate::ciphertext::public_parameters::PublicParameters;
+use crate::metrics::time_method;
 use crate::utils;
 
 use super::MyServerKey;
@@ -34,6 +35,7 @@ impl MyServerKey {
     /// assert_eq!(actual, "ZAMA");
     /// ```
     pub fn trim_end(&self, string: &FheString, public_parameters: &PublicParameters) -> FheString {
+        time_method!("trim_end");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
         let mut stop_trim_flag = zero.clone();
@@ -44,7 +46,7 @@ impl MyServerKey {
             let is_not_zero = string[i].ne(&self.key, &zero);
 
             let is_not_whitespace = string[i]
-                .is_whitespace(&self.key, public_parameters)
+                .is_whitespace(&self.key)
                 .flip(&self.key, public_parameters);
             stop_trim_flag = stop_trim_flag.bitor(
                 &self.key,
@@ -88,6 +90,7 @@ impl MyServerKey {
         string: &FheString,
         public_parameters: &PublicParameters,
     ) -> FheString {
+        time_method!("trim_start");
         let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
 
         let mut stop_trim_flag = zero.clone();
@@ -101,7 +104,7 @@ impl MyServerKey {
         for (i, result_char) in result.iter_mut().enumerate().take(string.len()) {
             let is_not_zero = string[i].ne(&self.key, &zero);
             let is_not_whitespace = string[i]
-                .is_whitespace(&self.key, public_parameters)
+                .is_whitespace(&self.key)
                 .flip(&self.key, public_parameters);
 
             stop_trim_flag = stop_trim_flag.bitor(
@@ -114,6 +117,142 @@ impl MyServerKey {
         utils::bubble_zeroes_right(result, &self.key, public_parameters)
     }
 
+    /// Trims trailing whitespace from a `FheString` and counts the trimmed characters.
+    ///
+    /// Same as `trim_end`, but also returns how many whitespace characters were removed, which
+    /// allows to reconstruct the original offsets after trimming. This costs one additional
+    /// encrypted addition per character.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string from which trailing whitespace will be trimmed.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `(FheString, FheAsciiChar)` - A new `FheString` with trailing whitespace removed and the
+    /// encrypted number of removed characters.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "ZAMA\n\t ";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let (trimmed, count) = my_server_key.trim_end_counted(&my_string, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt(trimmed), "ZAMA");
+    /// assert_eq!(my_client_key.decrypt_char(&count), 3u8);
+    /// ```
+    pub fn trim_end_counted(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> (FheString, FheAsciiChar) {
+        time_method!("trim_end_counted");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        let mut stop_trim_flag = zero.clone();
+        let mut trimmed_count = zero.clone();
+        let mut result = vec![zero.clone(); string.len()];
+
+        // Replace whitespace with \0 starting from the end, counting the replaced characters
+        for i in (0..string.len()).rev() {
+            let is_not_zero = string[i].ne(&self.key, &zero);
+
+            let is_not_whitespace = string[i]
+                .is_whitespace(&self.key)
+                .flip(&self.key, public_parameters);
+            stop_trim_flag = stop_trim_flag.bitor(
+                &self.key,
+                &is_not_whitespace.bitand(&self.key, &is_not_zero),
+            );
+            result[i] = stop_trim_flag.if_then_else(&self.key, &string[i], &zero);
+
+            let is_trimmed = stop_trim_flag
+                .flip(&self.key, public_parameters)
+                .bitand(&self.key, &is_not_zero);
+            trimmed_count = trimmed_count.add(&self.key, &is_trimmed);
+        }
+
+        (
+            FheString::from_vec(result, public_parameters, &self.key),
+            trimmed_count,
+        )
+    }
+
+    /// Trims leading whitespace from a `FheString` and counts the trimmed characters.
+    ///
+    /// Same as `trim_start`, but also returns how many whitespace characters were removed, which
+    /// is the position of the first non whitespace character in the original string. This costs
+    /// one additional encrypted addition per character.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string from which leading whitespace will be trimmed.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `(FheString, FheAsciiChar)` - A new `FheString` with leading whitespace removed and the
+    /// encrypted number of removed characters.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "\n\t ZAMA";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let (trimmed, count) = my_server_key.trim_start_counted(&my_string, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt(trimmed), "ZAMA");
+    /// assert_eq!(my_client_key.decrypt_char(&count), 3u8);
+    /// ```
+    pub fn trim_start_counted(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> (FheString, FheAsciiChar) {
+        time_method!("trim_start_counted");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        let mut stop_trim_flag = zero.clone();
+        let mut trimmed_count = zero.clone();
+        let mut result = FheString::from_vec(
+            vec![zero.clone(); string.len()],
+            public_parameters,
+            &self.key,
+        );
+
+        // Replace whitespace with \0 starting from the start, counting the replaced characters
+        for (i, result_char) in result.iter_mut().enumerate().take(string.len()) {
+            let is_not_zero = string[i].ne(&self.key, &zero);
+            let is_not_whitespace = string[i]
+                .is_whitespace(&self.key)
+                .flip(&self.key, public_parameters);
+
+            stop_trim_flag = stop_trim_flag.bitor(
+                &self.key,
+                &is_not_whitespace.bitand(&self.key, &is_not_zero),
+            );
+            *result_char = stop_trim_flag.if_then_else(&self.key, &string[i], &zero);
+
+            let is_trimmed = stop_trim_flag
+                .flip(&self.key, public_parameters)
+                .bitand(&self.key, &is_not_zero);
+            trimmed_count = trimmed_count.add(&self.key, &is_trimmed);
+        }
+
+        (
+            utils::bubble_zeroes_right(result, &self.key, public_parameters),
+            trimmed_count,
+        )
+    }
+
     /// Trims both leading and trailing whitespace from a `FheString`.
     ///
     /// This method removes both leading and trailing whitespace characters from the provided
@@ -144,7 +283,364 @@ impl MyServerKey {
     /// assert_eq!(actual, "ZAMA");
     /// ```
     pub fn trim(&self, string: &FheString, public_parameters: &PublicParameters) -> FheString {
+        time_method!("trim");
         let result = self.trim_end(string, public_parameters);
         self.trim_start(&result, public_parameters)
     }
+
+    /// Finds the span of the content of a `FheString` without its leading and trailing
+    /// whitespace.
+    ///
+    /// Returns the start and the end, exclusive, of `s.trim()` within `s`, so that the original
+    /// string can be sliced precisely. Like `str::trim`, an empty or all whitespace string
+    /// returns (0, 0).
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to search the content in.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `(FheAsciiChar, FheAsciiChar)` - The encrypted start and end of the trimmed content.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "  hi  ";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let (start, end) = my_server_key.trim_indices(&my_string, &public_parameters);
+    ///
+    /// assert_eq!(my_client_key.decrypt_char(&start), 2u8);
+    /// assert_eq!(my_client_key.decrypt_char(&end), 4u8);
+    /// ```
+    pub fn trim_indices(
+        &self,
+        string: &FheString,
+        public_parameters: &PublicParameters,
+    ) -> (FheAsciiChar, FheAsciiChar) {
+        time_method!("trim_indices");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+
+        let mut content_found = zero.clone();
+        let mut start = zero.clone();
+        let mut end = zero.clone();
+
+        for (i, string_char) in string.iter().enumerate() {
+            let is_not_zero = string_char.ne(&self.key, &zero);
+            let is_content = string_char
+                .is_whitespace(&self.key)
+                .flip(&self.key, public_parameters)
+                .bitand(&self.key, &is_not_zero);
+            content_found = content_found.bitor(&self.key, &is_content);
+
+            // Count the characters before the first content character
+            start = start.add(&self.key, &content_found.flip(&self.key, public_parameters));
+
+            // The end is right after the last content character
+            let enc_end =
+                FheAsciiChar::encrypt_trivial((i + 1) as u8, public_parameters, &self.key);
+            end = is_content.if_then_else(&self.key, &enc_end, &end);
+        }
+
+        // Without content the whole string was counted
+        start = content_found.if_then_else(&self.key, &start, &zero);
+
+        (start, end)
+    }
+
+    /// Removes all the consecutive occurrences of a character at the start of a `FheString`.
+    ///
+    /// Same as `s.trim_start_matches(ch)` for a single character, for example to strip the
+    /// leading zeros of a number. The occurrences are counted with `count_leading` and the rest
+    /// is shifted left by that amount, which is O(n log n) instead of the O(n^2) of `trim_start`.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to strip.
+    /// * `ch`: &FheAsciiChar - The character to remove.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string without its leading occurrences of `ch`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "000042";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let ch = my_client_key.encrypt_char(b'0');
+    ///
+    /// let res = my_server_key.strip_leading(&my_string, &ch, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "42");
+    /// ```
+    pub fn strip_leading(
+        &self,
+        string: &FheString,
+        ch: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("strip_leading");
+        let count = self.count_leading(string, ch, public_parameters);
+        let bytes = self.shift_left(string.get_bytes(), &count, string.len(), public_parameters);
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Removes all the consecutive occurrences of a character at the end of a `FheString`.
+    ///
+    /// Same as `s.trim_end_matches(ch)` for a single character. Nothing has to be shifted, the
+    /// removed characters become padding.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "4200";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let ch = my_client_key.encrypt_char(b'0');
+    ///
+    /// let res = my_server_key.strip_trailing(&my_string, &ch, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "42");
+    /// ```
+    pub fn strip_trailing(
+        &self,
+        string: &FheString,
+        ch: &FheAsciiChar,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("strip_trailing");
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let mut still_matching = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let mut bytes = string.get_bytes();
+
+        // The padding is only at the end, so it is all seen before the content
+        for byte in bytes.iter_mut().rev() {
+            let is_match = byte.eq(&self.key, ch);
+            let is_padding = byte.eq(&self.key, &zero);
+
+            still_matching =
+                still_matching.bitand(&self.key, &is_match.bitor(&self.key, &is_padding));
+            *byte = still_matching.if_then_else(&self.key, &zero, byte);
+        }
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Removes all the consecutive occurrences of a pattern at the start of a `FheString`.
+    ///
+    /// Same as `s.trim_start_matches(pattern)`. The occurrences can only follow each other from
+    /// the start, so there are at most `string.len() / pattern.len()` of them and the kth one can
+    /// only start at `k * pattern.len()`. The prefix is matched one occurrence at a time, a
+    /// partial occurrence is left in place, and the rest is shifted left by the number of
+    /// stripped characters like in `strip_leading`. An empty pattern leaves the string unchanged.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to strip.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to remove.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string without its leading occurrences of `pattern`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "ababzama";
+    /// let pattern_plain = "ab";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+    ///
+    /// let res = my_server_key.trim_start_matches(&my_string, &pattern, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "zama");
+    /// ```
+    pub fn trim_start_matches(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("trim_start_matches");
+        if pattern.is_empty() {
+            return string.clone();
+        }
+
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let pattern_len =
+            FheAsciiChar::encrypt_trivial(pattern.len() as u8, public_parameters, &self.key);
+
+        let mut still_matching = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let mut stripped = zero.clone();
+
+        // Only the offsets that are a multiple of the pattern length can continue the prefix
+        for is_match in self
+            .pattern_match_flags(string, pattern, public_parameters)
+            .iter()
+            .step_by(pattern.len())
+        {
+            still_matching = still_matching.bitand(&self.key, is_match);
+            stripped = stripped.add(
+                &self.key,
+                &still_matching.if_then_else(&self.key, &pattern_len, &zero),
+            );
+        }
+
+        let bytes = self.shift_left(
+            string.get_bytes(),
+            &stripped,
+            string.len(),
+            public_parameters,
+        );
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Removes all the consecutive occurrences of a plaintext pattern at the start of a
+    /// `FheString`.
+    ///
+    /// Same as `trim_start_matches` but with a plaintext pattern.
+    pub fn trim_start_matches_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("trim_start_matches_clear");
+        let pattern = clear_pattern
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        self.trim_start_matches(string, &pattern, public_parameters)
+    }
+
+    /// Removes all the consecutive occurrences of a pattern at the end of a `FheString`.
+    ///
+    /// Same as `s.trim_end_matches(pattern)`. An occurrence is trailing if it is followed by the
+    /// padding or by another trailing occurrence, so the occurrences are chained from the back
+    /// like the padding handling of `strip_suffix`, over at most `string.len() / pattern.len()`
+    /// of them. The removed characters become padding. A string made only of the pattern is
+    /// trimmed to an empty string, and an empty pattern leaves the string unchanged.
+    ///
+    /// # Arguments
+    /// * `string`: &FheString - The string to strip.
+    /// * `pattern`: &[FheAsciiChar] - The unpadded pattern to remove.
+    /// * `public_parameters`: &PublicParameters - Public parameters for FHE operations.
+    ///
+    /// # Returns
+    /// `FheString` - The string without its trailing occurrences of `pattern`.
+    ///
+    /// # Example:
+    /// ```
+    /// let my_string_plain = "zamaabab";
+    /// let pattern_plain = "ab";
+    ///
+    /// let my_string = my_client_key.encrypt(
+    ///     my_string_plain,
+    ///     STRING_PADDING,
+    ///     &public_parameters,
+    ///     &my_server_key.key,
+    /// );
+    /// let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+    ///
+    /// let res = my_server_key.trim_end_matches(&my_string, &pattern, &public_parameters);
+    /// let actual = my_client_key.decrypt(res);
+    ///
+    /// assert_eq!(actual, "zama");
+    /// ```
+    pub fn trim_end_matches(
+        &self,
+        string: &FheString,
+        pattern: &[FheAsciiChar],
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("trim_end_matches");
+        if pattern.is_empty() {
+            return string.clone();
+        }
+
+        let zero = FheAsciiChar::encrypt_trivial(0u8, public_parameters, &self.key);
+        let one = FheAsciiChar::encrypt_trivial(1u8, public_parameters, &self.key);
+        let pattern_flags = self.pattern_match_flags(string, pattern, public_parameters);
+
+        // The kth entry is set if a trailing occurrence starts at k
+        let mut is_trailing = vec![zero.clone(); pattern_flags.len()];
+
+        for i in (0..pattern_flags.len()).rev() {
+            let next = i + pattern.len();
+            let is_followed_by_end = if next == string.len() {
+                one.clone()
+            } else if next < pattern_flags.len() {
+                string[next]
+                    .eq(&self.key, &zero)
+                    .bitor(&self.key, &is_trailing[next])
+            } else {
+                string[next].eq(&self.key, &zero)
+            };
+
+            is_trailing[i] = pattern_flags[i].bitand(&self.key, &is_followed_by_end);
+        }
+
+        let mut bytes = string.get_bytes();
+        for (i, trailing) in is_trailing.iter().enumerate() {
+            for byte in bytes.iter_mut().skip(i).take(pattern.len()) {
+                *byte = trailing.if_then_else(&self.key, &zero, byte);
+            }
+        }
+
+        FheString::from_vec(bytes, public_parameters, &self.key)
+    }
+
+    /// Removes all the consecutive occurrences of a plaintext pattern at the end of a
+    /// `FheString`.
+    ///
+    /// Same as `trim_end_matches` but with a plaintext pattern.
+    pub fn trim_end_matches_clear(
+        &self,
+        string: &FheString,
+        clear_pattern: &str,
+        public_parameters: &PublicParameters,
+    ) -> FheString {
+        time_method!("trim_end_matches_clear");
+        let pattern = clear_pattern
+            .bytes()
+            .map(|b| FheAsciiChar::encrypt_trivial(b, public_parameters, &self.key))
+            .collect::<Vec<FheAsciiChar>>();
+
+        self.trim_end_matches(string, &pattern, public_parameters)
+    }
+
+    /// Alias of `trim_start`, for users used to the `ltrim` name of other languages.
+    pub fn ltrim(&self, string: &FheString, public_parameters: &PublicParameters) -> FheString {
+        time_method!("ltrim");
+        self.trim_start(string, public_parameters)
+    }
+
+    /// Alias of `trim_end`, for users used to the `rtrim` name of other languages.
+    pub fn rtrim(&self, string: &FheString, public_parameters: &PublicParameters) -> FheString {
+        time_method!("rtrim");
+        self.trim_end(string, public_parameters)
+    }
 }
@@ -0,0 +1,4150 @@
+use crate::ciphertext::envelope::{parameters_fingerprint, EnvelopeError};
+use crate::ciphertext::fhesplit::FheSplit;
+use crate::ciphertext::fhestrip::FheStrip;
+use crate::ciphertext::stringops::StringOps;
+use crate::server_key::fhestringops::FheStringOps;
+use crate::server_key::MyServerKey;
+use crate::utils::{trim_str_vector, trim_vector};
+use crate::{
+    FheAsciiChar, FheString, MyClientKey, PublicParameters, MAX_FIND_LENGTH, MAX_REPETITIONS,
+    STRING_PADDING,
+};
+use tfhe::shortint::prelude::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+fn setup_test() -> (MyClientKey, MyServerKey, PublicParameters) {
+    let num_blocks = 4;
+
+    // Construct custom key types from tfhe-rs keys, based on the default parameters
+    let my_client_key = MyClientKey::from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    let my_server_key = my_client_key.get_server_key();
+    let public_parameters = my_client_key.get_public_parameters();
+
+    (my_client_key, my_server_key, public_parameters)
+}
+
+#[test]
+fn valid_contains() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "awesomezamaisawesome";
+    let needle_plain = "zama";
+
+    let heistack = my_client_key.encrypt(heistack_plain, 3, &public_parameters, &my_server_key.key);
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.contains(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.contains(needle_plain);
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn trivial_encryption() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "awesome zama is awesome";
+    let needle_plain = "zama";
+
+    let heistack = my_client_key.encrypt_trivial_string(heistack_plain, STRING_PADDING);
+    let needle = my_client_key.encrypt_trivial_no_padding(needle_plain);
+
+    let res = my_server_key.contains(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    assert_eq!(dec, heistack_plain.contains(needle_plain) as u8);
+
+    let my_string_upper = my_server_key.to_upper(&heistack, &public_parameters);
+    let actual = my_client_key.decrypt(my_string_upper);
+
+    assert_eq!(actual, heistack_plain.to_uppercase());
+}
+
+#[test]
+fn degenerate_inputs() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // Empty strings, empty patterns and patterns longer than the string
+    for (my_string_plain, pattern_plain) in [("", "a"), ("", ""), ("ab", ""), ("ab", "abcd")] {
+        let my_string = my_client_key.encrypt_trivial_string(my_string_plain, STRING_PADDING);
+        let pattern = my_client_key.encrypt_trivial_no_padding(pattern_plain);
+
+        let res = my_server_key.contains(&my_string, &pattern, &public_parameters);
+        assert_eq!(
+            my_client_key.decrypt_char(&res),
+            my_string_plain.contains(pattern_plain) as u8
+        );
+
+        let res = my_server_key.starts_with(&my_string, &pattern, &public_parameters);
+        assert_eq!(
+            my_client_key.decrypt_char(&res),
+            my_string_plain.starts_with(pattern_plain) as u8
+        );
+
+        let res = my_server_key.ends_with(&my_string, &pattern, &public_parameters);
+        assert_eq!(
+            my_client_key.decrypt_char(&res),
+            my_string_plain.ends_with(pattern_plain) as u8
+        );
+
+        let res = my_server_key.find(&my_string, &pattern, &public_parameters);
+        assert_eq!(
+            my_client_key.decrypt_char(&res),
+            my_string_plain
+                .find(pattern_plain)
+                .unwrap_or(MAX_FIND_LENGTH) as u8
+        );
+
+        let res = my_server_key.rfind(my_string.clone(), &pattern, &public_parameters);
+        assert_eq!(
+            my_client_key.decrypt_char(&res),
+            my_string_plain
+                .rfind(pattern_plain)
+                .unwrap_or(MAX_FIND_LENGTH) as u8
+        );
+
+        let res = my_server_key.replace(&my_string, &pattern, &pattern, &public_parameters);
+        assert_eq!(
+            my_client_key.decrypt(res),
+            my_string_plain.replace(pattern_plain, pattern_plain)
+        );
+
+        // The empty pattern is covered by the split_empty_pattern tests
+        if pattern_plain.is_empty() {
+            continue;
+        }
+
+        let fhe_split = my_server_key.split(&my_string, &pattern, &public_parameters);
+        let (plain_split, _) = FheSplit::decrypt(fhe_split, &my_client_key);
+        let expected: Vec<&str> = my_string_plain.split(pattern_plain).collect();
+        assert_eq!(trim_vector(plain_split), trim_str_vector(expected));
+
+        let fhe_split = my_server_key.rsplit(&my_string, &pattern, &public_parameters);
+        let (plain_split, _) = FheSplit::decrypt(fhe_split, &my_client_key);
+        let expected: Vec<&str> = my_string_plain.rsplit(pattern_plain).collect();
+        assert_eq!(trim_vector(plain_split), trim_str_vector(expected));
+
+        let fhe_split = my_server_key.split_terminator(&my_string, &pattern, &public_parameters);
+        let (plain_split, _) = FheSplit::decrypt(fhe_split, &my_client_key);
+        let expected: Vec<&str> = my_string_plain.split_terminator(pattern_plain).collect();
+        assert_eq!(trim_vector(plain_split), trim_str_vector(expected));
+
+        let fhe_split = my_server_key.split_inclusive(&my_string, &pattern, &public_parameters);
+        let (plain_split, _) = FheSplit::decrypt(fhe_split, &my_client_key);
+        let expected: Vec<&str> = my_string_plain.split_inclusive(pattern_plain).collect();
+        assert_eq!(trim_vector(plain_split), trim_str_vector(expected));
+    }
+
+    // Normalizing an empty string must not underflow
+    let empty = my_client_key.encrypt_trivial_no_padding("");
+    let normalized = FheString::from_vec_normalized(empty, &public_parameters, &my_server_key.key);
+    assert_eq!(my_client_key.decrypt(normalized), "");
+}
+
+#[test]
+fn from_vec_normalized() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let zero = FheAsciiChar::encrypt_trivial(0u8, &public_parameters, &my_server_key.key);
+    let mut bytes = my_client_key.encrypt_no_padding("a");
+    bytes.push(zero);
+    bytes.append(&mut my_client_key.encrypt_no_padding("b"));
+
+    let raw = FheString::from_vec(bytes.clone(), &public_parameters, &my_server_key.key);
+    let normalized = FheString::from_vec_normalized(bytes, &public_parameters, &my_server_key.key);
+
+    // Decryption stops at the first \0, so only the normalized string is complete
+    assert_eq!(my_client_key.decrypt(raw), "a");
+    assert_eq!(my_client_key.decrypt(normalized), "ab");
+}
+
+#[test]
+fn invalid_contains() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello world";
+    let needle_plain = "zama";
+
+    let heistack = my_client_key.encrypt(heistack_plain, 3, &public_parameters, &my_server_key.key);
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.contains(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.contains(needle_plain);
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn invalid_ends_with() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello world";
+    let needle_plain = "zama";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.ends_with(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.ends_with(needle_plain);
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn starts_with_len() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello world";
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for needle_plain in ["hello", "world", "", "hello world!"] {
+        let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+        let (matched, consumed) =
+            my_server_key.starts_with_len(&heistack, &needle, &public_parameters);
+
+        let expected = match heistack_plain.strip_prefix(needle_plain) {
+            Some(_) => (1u8, needle_plain.len() as u8),
+            None => (0u8, 0u8),
+        };
+
+        assert_eq!(
+            (
+                my_client_key.decrypt_char(&matched),
+                my_client_key.decrypt_char(&consumed)
+            ),
+            expected
+        );
+    }
+}
+
+#[test]
+fn starts_with_any() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let prefixes_plain = ["GET", "POST"];
+    let prefixes = prefixes_plain
+        .iter()
+        .map(|prefix| my_client_key.encrypt_no_padding(prefix))
+        .collect::<Vec<Vec<FheAsciiChar>>>();
+
+    for heistack_plain in ["POST /x", "GET /", "PUT /x", "GE", ""] {
+        let heistack = my_client_key.encrypt(
+            heistack_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.starts_with_any(&heistack, &prefixes, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        let expected = prefixes_plain
+            .iter()
+            .any(|prefix| heistack_plain.starts_with(prefix));
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn match_prefix() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let prefixes_plain = ["GET", "POST", "GETX"];
+    let prefixes = prefixes_plain
+        .iter()
+        .map(|prefix| my_client_key.encrypt_no_padding(prefix))
+        .collect::<Vec<Vec<FheAsciiChar>>>();
+
+    for heistack_plain in ["POST /", "GETX /", "PUT /", ""] {
+        let heistack = my_client_key.encrypt(
+            heistack_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.match_prefix(&heistack, &prefixes, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        let expected = prefixes_plain
+            .iter()
+            .position(|prefix| heistack_plain.starts_with(prefix))
+            .unwrap_or(MAX_FIND_LENGTH);
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn windowed_eq() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "ababa";
+    let needle_plain = "aba";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let flags = my_server_key.windowed_eq(&heistack, &needle, &public_parameters);
+    let dec = flags
+        .iter()
+        .map(|flag| my_client_key.decrypt_char(flag))
+        .collect::<Vec<u8>>();
+
+    // One flag for every offset at which the needle fits, padding included
+    let expected = (0..=heistack_plain.len() + STRING_PADDING - needle_plain.len())
+        .map(|i| {
+            heistack_plain
+                .get(i..)
+                .is_some_and(|s| s.starts_with(needle_plain)) as u8
+        })
+        .collect::<Vec<u8>>();
+
+    assert_eq!(dec, expected);
+}
+
+#[test]
+fn pattern_match_consumers() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "abcabcab";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    // The search methods share the same scan, check them together on the same inputs
+    for needle_plain in ["abc", "cab", "b", "abcabcab", "abcabcabc", "x"] {
+        let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+        let res = my_server_key.contains(&heistack, &needle, &public_parameters);
+        let expected = heistack_plain.contains(needle_plain);
+        assert_eq!(my_client_key.decrypt_char(&res), expected as u8);
+
+        let res = my_server_key.find(&heistack, &needle, &public_parameters);
+        let expected = heistack_plain.find(needle_plain).unwrap_or(MAX_FIND_LENGTH);
+        assert_eq!(my_client_key.decrypt_char(&res), expected as u8);
+
+        let res = my_server_key.rfind(heistack.clone(), &needle, &public_parameters);
+        let expected = heistack_plain
+            .rfind(needle_plain)
+            .unwrap_or(MAX_FIND_LENGTH);
+        assert_eq!(my_client_key.decrypt_char(&res), expected as u8);
+
+        let fhe_split = my_server_key.split(&heistack, &needle, &public_parameters);
+        let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+        let expected: Vec<&str> = heistack_plain.split(needle_plain).collect();
+        assert_eq!(trim_vector(plain_split.0), trim_str_vector(expected));
+    }
+}
+
+#[test]
+fn valid_starts_with() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello world";
+    let needle_plain = "hello";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.starts_with(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.starts_with(needle_plain);
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn invalid_starts_with() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello world";
+    let needle_plain = "zama";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.starts_with(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.starts_with(needle_plain);
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn valid_ends_with() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello world";
+    let needle_plain = "world";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.ends_with(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.ends_with(needle_plain);
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn uppercase() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "zama IS awesome";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string_upper = my_server_key.to_upper(&my_string, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_string_upper);
+    let expected = my_string_plain.to_uppercase();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn multi_bit_parameters() {
+    let my_client_key = MyClientKey::default_fast(4);
+    let my_server_key = my_client_key.get_server_key();
+    let public_parameters = my_client_key.get_public_parameters();
+
+    let my_string_plain = "zama IS awesome";
+    let other_plain = " indeed";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let other = my_client_key.encrypt(
+        other_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    // The case conversions add and subtract 32, which must not overflow the message space
+    let upper = my_server_key.to_upper(&my_string, &public_parameters);
+    assert_eq!(my_client_key.decrypt(upper), my_string_plain.to_uppercase());
+
+    let lower = my_server_key.to_lower(&my_string, &public_parameters);
+    assert_eq!(my_client_key.decrypt(lower), my_string_plain.to_lowercase());
+
+    let contains = my_server_key.contains_clear(&my_string, "IS", &public_parameters);
+    assert_eq!(my_client_key.decrypt_char(&contains), 1u8);
+
+    let len = my_server_key.len(&my_string, &public_parameters);
+    assert_eq!(
+        my_client_key.decrypt_char(&len),
+        my_string_plain.len() as u8
+    );
+
+    let concatenated = my_server_key.concatenate(&my_string, &other, &public_parameters);
+    assert_eq!(
+        my_client_key.decrypt(concatenated),
+        format!("{}{}", my_string_plain, other_plain)
+    );
+}
+
+#[test]
+fn zfill_signed() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // Same as Python's str.zfill
+    let zfill = |s: &str, width: usize| {
+        let fill = "0".repeat(width.saturating_sub(s.len()));
+        match s.strip_prefix(['+', '-']) {
+            Some(rest) => format!("{}{}{}", &s[..1], fill, rest),
+            None => format!("{}{}", fill, s),
+        }
+    };
+
+    for (my_string_plain, width) in [
+        ("-5", 4),
+        ("+5", 4),
+        ("5", 4),
+        ("-", 3),
+        ("+", 2),
+        ("12345", 3),
+        ("-42", 3),
+        ("", 3),
+    ] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.zfill_signed(&my_string, width, &public_parameters);
+        let actual = my_client_key.decrypt(res);
+
+        assert_eq!(actual, zfill(my_string_plain, width));
+    }
+
+    // No character at all, not even padding
+    let my_string = my_client_key.encrypt("", 0, &public_parameters, &my_server_key.key);
+    let res = my_server_key.zfill_signed(&my_string, 0, &public_parameters);
+    assert_eq!(res.len(), 0);
+}
+
+#[test]
+fn pad_to_block_multiple() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (my_string_plain, block) in [("abcde", 4), ("abcd", 4), ("", 4), ("abc", 1)] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let fill = my_client_key.encrypt_char(b'#');
+
+        let padded =
+            my_server_key.pad_to_block_multiple(&my_string, block, fill, &public_parameters);
+        let actual = my_client_key.decrypt(padded);
+
+        let padded_len = my_string_plain.len().div_ceil(block) * block;
+        let expected = format!("{:#<padded_len$}", my_string_plain);
+
+        assert_eq!(actual, expected);
+    }
+
+    // The padding spans more than the block of the end of the content, the next one is left
+    let my_string = my_client_key.encrypt("abcde", 7, &public_parameters, &my_server_key.key);
+    let fill = my_client_key.encrypt_char(b'#');
+
+    let padded = my_server_key.pad_to_block_multiple(&my_string, 4, fill, &public_parameters);
+    let actual = my_client_key.decrypt(padded);
+
+    assert_eq!(actual, "abcde###");
+}
+
+#[test]
+fn repeat() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "abc";
+    let n_plain = 3u8;
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let n = my_client_key.encrypt_char(n_plain);
+
+    let my_string_upper = my_server_key.repeat(&my_string, n, &public_parameters);
+    let actual = my_client_key.decrypt(my_string_upper);
+    let expected = my_string_plain.repeat(n_plain.into());
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn repeat_clear_truncated() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "ab";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    // A huge plaintext value does not make the output grow without bound
+    let my_new_string = my_server_key.repeat_clear(&my_string, usize::MAX, &public_parameters);
+
+    assert_eq!(
+        my_client_key.decrypt(my_new_string),
+        my_string_plain.repeat(MyServerKey::MAX_REPETITIONS)
+    );
+}
+
+#[test]
+fn repeat_checked() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "a";
+    let n_plain = MAX_REPETITIONS as u8 + 1;
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let n = my_client_key.encrypt_char(n_plain);
+
+    let (my_string_repeated, overflow) =
+        my_server_key.repeat_checked(&my_string, n, &public_parameters);
+    let actual = my_client_key.decrypt(my_string_repeated);
+
+    // The output is truncated and the truncation is flagged
+    assert_eq!(actual, my_string_plain.repeat(MAX_REPETITIONS));
+    assert_eq!(my_client_key.decrypt_char(&overflow), 1u8);
+}
+
+#[test]
+fn replace1() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello world world test";
+    let from_plain = "world";
+    let to_plain = "abc";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let from = my_client_key.encrypt_no_padding(from_plain);
+    let to = my_client_key.encrypt_no_padding(to_plain);
+
+    let my_new_string = my_server_key.replace(&my_string, &from, &to, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_new_string);
+    let expected = my_string_plain.replace(from_plain, to_plain);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn replace2() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello abc abc test";
+    let from_plain = "abc";
+    let to_plain = "world";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let from = my_client_key.encrypt_no_padding(from_plain);
+    let to = my_client_key.encrypt_no_padding(to_plain);
+
+    let my_new_string = my_server_key.replace(&my_string, &from, &to, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_new_string);
+    let expected = my_string_plain.replace(from_plain, to_plain);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn replace_overlapping() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "aaa";
+    let from_plain = "aa";
+    let to_plain = "b";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let from = my_client_key.encrypt_no_padding(from_plain);
+    let to = my_client_key.encrypt_no_padding(to_plain);
+
+    let my_new_string = my_server_key.replace(&my_string, &from, &to, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_new_string);
+    let expected = my_string_plain.replace(from_plain, to_plain);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn zero_padding() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "abcab";
+
+    let my_string =
+        my_client_key.encrypt(my_string_plain, 0, &public_parameters, &my_server_key.key);
+
+    for pattern_plain in ["ab", "b", ""] {
+        let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+        let res = my_server_key.rfind(my_string.clone(), &pattern, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+        let expected = my_string_plain.rfind(pattern_plain).unwrap();
+
+        assert_eq!(dec as usize, expected);
+    }
+
+    // Both the longer and the shorter from cases
+    for (from_plain, to_plain) in [("ab", "x"), ("b", "xyz"), ("c", "")] {
+        let from = my_client_key.encrypt_no_padding(from_plain);
+        let to = my_client_key.encrypt_no_padding(to_plain);
+
+        let my_new_string = my_server_key.replace(&my_string, &from, &to, &public_parameters);
+        let actual = my_client_key.decrypt(my_new_string);
+        let expected = my_string_plain.replace(from_plain, to_plain);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn replace_first() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "a-a-a";
+    let from_plain = "a";
+    let to_plain = "X";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let from = my_client_key.encrypt_no_padding(from_plain);
+    let to = my_client_key.encrypt_no_padding(to_plain);
+
+    let my_new_string = my_server_key.replace_first(&my_string, &from, &to, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_new_string);
+    let expected = my_string_plain.replacen(from_plain, to_plain, 1);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn replacen() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello abc abc test";
+    let from_plain = "abc";
+    let to_plain = "world";
+    let n_plain = 1u8;
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let from = my_client_key.encrypt_no_padding(from_plain);
+    let to = my_client_key.encrypt_no_padding(to_plain);
+    let n = my_client_key.encrypt_char(n_plain);
+
+    let my_new_string = my_server_key.replacen(&my_string, &from, &to, n, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_new_string);
+    let expected = my_string_plain.replacen(from_plain, to_plain, n_plain.into());
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn replace_range() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "name:JOHN;age:42";
+    let range = 5..9;
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for replacement_plain in ["ALEXANDER", "JANE", "AL", ""] {
+        let replacement = my_client_key.encrypt_no_padding(replacement_plain);
+
+        let my_new_string = my_server_key.replace_range(
+            &my_string,
+            range.clone(),
+            &replacement,
+            &public_parameters,
+        );
+
+        let actual = my_client_key.decrypt(my_new_string);
+        let mut expected = my_string_plain.to_owned();
+        expected.replace_range(range.clone(), replacement_plain);
+
+        assert_eq!(actual, expected);
+    }
+
+    // A range in the padding appends the replacement, whether it has the length of the range
+    // or not
+    let my_string = my_client_key.encrypt("ab", 3, &public_parameters, &my_server_key.key);
+
+    for replacement_plain in ["x", "xy"] {
+        let replacement = my_client_key.encrypt_no_padding(replacement_plain);
+
+        let my_new_string =
+            my_server_key.replace_range(&my_string, 3..4, &replacement, &public_parameters);
+        let actual = my_client_key.decrypt(my_new_string);
+
+        assert_eq!(actual, format!("ab{}", replacement_plain));
+    }
+}
+
+#[test]
+fn lowercase() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "zama IS awesome";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string_upper = my_server_key.to_lower(&my_string, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_string_upper);
+    let expected = my_string_plain.to_lowercase();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn to_upper_to_lower_mut() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "zama IS awesome";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let mut my_string_upper = my_string.clone();
+    my_server_key.to_upper_mut(&mut my_string_upper, &public_parameters);
+    let mut my_string_lower = my_string.clone();
+    my_server_key.to_lower_mut(&mut my_string_lower, &public_parameters);
+
+    // Same result as the allocating versions
+    let expected_upper = my_server_key.to_upper(&my_string, &public_parameters);
+    let expected_lower = my_server_key.to_lower(&my_string, &public_parameters);
+
+    assert_eq!(
+        my_client_key.decrypt(my_string_upper),
+        my_client_key.decrypt(expected_upper)
+    );
+    assert_eq!(
+        my_client_key.decrypt(my_string_lower),
+        my_client_key.decrypt(expected_lower)
+    );
+}
+
+#[test]
+fn trim_end() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "ZA MA\n\t \r\x0C";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string_upper = my_server_key.trim_end(&my_string, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_string_upper);
+    let expected = my_string_plain.trim_end();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn do_not_trim_end() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "\nZA MA";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string_upper = my_server_key.trim_end(&my_string, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_string_upper);
+    let expected = my_string_plain.trim_end();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn trim_start() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "\nZA MA";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string_upper = my_server_key.trim_start(&my_string, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_string_upper);
+    let expected = my_string_plain.trim_start();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn trim() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "\nZA MA\n";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string_upper = my_server_key.trim(&my_string, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_string_upper);
+    let expected = my_string_plain.trim();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn trim_indices() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in ["  hi  ", "hi", " a b\t", "   ", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let (start, end) = my_server_key.trim_indices(&my_string, &public_parameters);
+        let start = my_client_key.decrypt_char(&start) as usize;
+        let end = my_client_key.decrypt_char(&end) as usize;
+
+        // std returns the empty slice at 0 for an all whitespace string
+        let trimmed = my_string_plain.trim();
+        let expected_start = if trimmed.is_empty() {
+            0
+        } else {
+            trimmed.as_ptr() as usize - my_string_plain.as_ptr() as usize
+        };
+
+        assert_eq!(
+            (start, end),
+            (expected_start, expected_start + trimmed.len())
+        );
+    }
+}
+
+#[test]
+fn trim_all_whitespace() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // Every character for which `is_whitespace` returns true, on both ends
+    for whitespace in [' ', '\t', '\n', '\x0B', '\x0C', '\r'] {
+        let my_string_plain = format!("{whitespace}{whitespace}ZA{whitespace}MA{whitespace}");
+
+        let my_string = my_client_key.encrypt(
+            &my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let trimmed = my_server_key.trim(&my_string, &public_parameters);
+        let trimmed_start = my_server_key.ltrim(&my_string, &public_parameters);
+        let trimmed_end = my_server_key.rtrim(&my_string, &public_parameters);
+
+        assert_eq!(my_client_key.decrypt(trimmed), my_string_plain.trim());
+        assert_eq!(
+            my_client_key.decrypt(trimmed_start),
+            my_string_plain.trim_start()
+        );
+        assert_eq!(
+            my_client_key.decrypt(trimmed_end),
+            my_string_plain.trim_end()
+        );
+    }
+}
+
+#[test]
+fn trim_counted() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in ["\n\t ZA MA \r", "ZA MA", " \n ", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let (trimmed_start, start_count) =
+            my_server_key.trim_start_counted(&my_string, &public_parameters);
+        let (trimmed_end, end_count) =
+            my_server_key.trim_end_counted(&my_string, &public_parameters);
+
+        let expected_start = my_string_plain.trim_start();
+        let expected_end = my_string_plain.trim_end();
+
+        assert_eq!(my_client_key.decrypt(trimmed_start), expected_start);
+        assert_eq!(
+            my_client_key.decrypt_char(&start_count) as usize,
+            my_string_plain.len() - expected_start.len()
+        );
+        assert_eq!(my_client_key.decrypt(trimmed_end), expected_end);
+        assert_eq!(
+            my_client_key.decrypt_char(&end_count) as usize,
+            my_string_plain.len() - expected_end.len()
+        );
+    }
+}
+
+#[test]
+fn len_upper_bound() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.len(&my_string, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    assert_eq!(
+        my_string.len_upper_bound(),
+        my_string_plain.len() + STRING_PADDING
+    );
+    assert!(dec as usize <= my_string.len_upper_bound());
+}
+
+#[test]
+fn from_ascii_bytes() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let bytes = b"zama\t42";
+
+    let my_string = FheString::from_ascii_bytes(
+        bytes,
+        STRING_PADDING,
+        &my_client_key,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    assert_eq!(my_client_key.decrypt_chars(&my_string), bytes);
+
+    // Non ASCII bytes round trip when the check is skipped
+    let bytes = [0x7Au8, 0xC8u8, 0xFFu8];
+
+    let my_string = FheString::from_bytes_unchecked(
+        &bytes,
+        STRING_PADDING,
+        &my_client_key,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    assert_eq!(my_client_key.decrypt_chars(&my_string), bytes);
+}
+
+#[test]
+#[should_panic(expected = "must only contain ascii letters")]
+fn from_ascii_bytes_non_ascii() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    FheString::from_ascii_bytes(
+        &[0x7Au8, 0xC8u8],
+        STRING_PADDING,
+        &my_client_key,
+        &public_parameters,
+        &my_server_key.key,
+    );
+}
+
+#[test]
+fn decrypt_lossy_checked() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string = my_client_key.encrypt(
+        "zama",
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    assert_eq!(my_client_key.decrypt_lossy(&my_string), "zama");
+    assert_eq!(
+        my_client_key.decrypt_checked(&my_string),
+        Ok("zama".to_owned())
+    );
+
+    // Adding to the characters can leave the ASCII range
+    let offset = FheAsciiChar::encrypt_trivial(0x50u8, &public_parameters, &my_server_key.key);
+    let mut shifted = my_string.clone();
+    shifted[0] = shifted[0].add(&my_server_key.key, &offset);
+
+    assert_eq!(my_client_key.decrypt_lossy(&shifted), "?ama");
+    assert_eq!(
+        my_client_key.decrypt_checked(&shifted),
+        Err(vec![b'z' + 0x50, b'a', b'm', b'a'])
+    );
+}
+
+#[test]
+fn encrypt_chunks() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (my_string_plain, chunk_len) in [("hello world", 4), ("abcd", 2), ("abc", 10), ("", 3)] {
+        let chunks = my_client_key
+            .encrypt_chunks(
+                my_string_plain,
+                chunk_len,
+                STRING_PADDING,
+                &public_parameters,
+                &my_server_key.key,
+            )
+            .map(|chunk| my_client_key.decrypt(chunk))
+            .collect::<Vec<String>>();
+
+        let expected = my_string_plain
+            .as_bytes()
+            .chunks(chunk_len)
+            .map(|chunk| String::from_utf8(chunk.to_vec()).unwrap())
+            .collect::<Vec<String>>();
+
+        assert_eq!(chunks, expected);
+    }
+}
+
+#[test]
+fn encrypt_auto() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "zama";
+
+    let my_string =
+        my_client_key.encrypt_auto(my_string_plain, &public_parameters, &my_server_key.key);
+
+    assert_eq!(
+        my_string.len_upper_bound(),
+        my_string_plain.len() + STRING_PADDING
+    );
+
+    // The default padding is enough for the methods that need an extra \0
+    let res = my_server_key.rfind_clear(&my_string, "a", &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+    assert_eq!(dec as usize, my_string_plain.rfind('a').unwrap());
+
+    let my_new_string = my_server_key.replace_clear(&my_string, "a", "aa", &public_parameters);
+    assert_eq!(
+        my_client_key.decrypt(my_new_string),
+        my_string_plain.replace('a', "aa")
+    );
+}
+
+#[test]
+fn serialized_size() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string = my_client_key.encrypt(
+        "hello",
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_char = my_client_key.encrypt_char(b'h');
+
+    // Every character, padding included, has the same ciphertext size
+    assert!(my_char.serialized_size() > 0);
+    assert_eq!(
+        my_string.serialized_size(),
+        my_string.len_upper_bound() * my_char.serialized_size()
+    );
+}
+
+#[test]
+fn can_decrypt() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string = my_client_key.encrypt(
+        "hello",
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    assert!(my_client_key.can_decrypt(&my_string));
+
+    // Characters after the padding and non ASCII values are what a corrupted string looks like
+    for bytes in [vec![b'a', 0u8, b'b'], vec![b'a', 0xC8u8]] {
+        let corrupted = FheString::from_vec(
+            bytes
+                .into_iter()
+                .map(|b| my_client_key.encrypt_char(b))
+                .collect(),
+            &public_parameters,
+            &my_server_key.key,
+        );
+        assert!(!my_client_key.can_decrypt(&corrupted));
+    }
+}
+
+#[test]
+fn envelope() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string = my_client_key.encrypt(
+        "zama",
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let envelope = my_string.to_envelope(&public_parameters);
+
+    let restored = FheString::from_envelope(&envelope, &public_parameters).unwrap();
+    assert_eq!(my_client_key.decrypt(restored), "zama");
+
+    // Loading with the public parameters of other keys must fail instead of decrypting garbage
+    let (_, _, other_public_parameters) = setup_test();
+    assert!(matches!(
+        FheString::from_envelope(&envelope, &other_public_parameters),
+        Err(EnvelopeError::ParametersMismatch { .. })
+    ));
+
+    assert!(matches!(
+        FheString::from_envelope(&envelope[..6], &public_parameters),
+        Err(EnvelopeError::Truncated)
+    ));
+
+    let mut bad_magic = envelope.clone();
+    bad_magic[0] = b'X';
+    assert!(matches!(
+        FheString::from_envelope(&bad_magic, &public_parameters),
+        Err(EnvelopeError::BadMagic)
+    ));
+
+    let mut newer_version = envelope.clone();
+    newer_version[4..6].copy_from_slice(&u16::MAX.to_le_bytes());
+    assert!(matches!(
+        FheString::from_envelope(&newer_version, &public_parameters),
+        Err(EnvelopeError::UnsupportedVersion(u16::MAX))
+    ));
+
+    assert!(matches!(
+        FheString::from_envelope(&envelope[..envelope.len() - 1], &public_parameters),
+        Err(EnvelopeError::Payload(_))
+    ));
+
+    // A precomputed fingerprint gives the same envelopes
+    let fingerprint = parameters_fingerprint(&public_parameters);
+    assert_eq!(
+        my_string.to_envelope_with_fingerprint(fingerprint),
+        envelope
+    );
+    let restored = FheString::from_envelope_with_fingerprint(&envelope, fingerprint).unwrap();
+    assert_eq!(my_client_key.decrypt(restored), "zama");
+    assert!(matches!(
+        FheString::from_envelope_with_fingerprint(&envelope, fingerprint ^ 1),
+        Err(EnvelopeError::ParametersMismatch { .. })
+    ));
+}
+
+#[test]
+fn refresh() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "Hello World";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    // Refresh in the middle of a pipeline
+    let my_new_string = my_server_key.to_upper(&my_string, &public_parameters);
+    let my_new_string = my_server_key.refresh(&my_new_string, &public_parameters);
+    let my_new_string = my_server_key.to_lower(&my_new_string, &public_parameters);
+
+    assert!(my_client_key.can_decrypt(&my_new_string));
+    assert_eq!(
+        my_client_key.decrypt(my_new_string),
+        my_string_plain.to_uppercase().to_lowercase()
+    );
+}
+
+#[test]
+fn is_empty() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "";
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.is_empty(&my_string, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+    let expected = my_string_plain.is_empty();
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn count_leading_trailing() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let ch = my_client_key.encrypt_char(b'0');
+
+    for my_string_plain in ["000042", "420", "0000", "42", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let leading = my_server_key.count_leading(&my_string, &ch, &public_parameters);
+        let trailing = my_server_key.count_trailing(&my_string, &ch, &public_parameters);
+
+        let expected_leading =
+            my_string_plain.len() - my_string_plain.trim_start_matches('0').len();
+        let expected_trailing = my_string_plain.len() - my_string_plain.trim_end_matches('0').len();
+
+        assert_eq!(my_client_key.decrypt_char(&leading), expected_leading as u8);
+        assert_eq!(
+            my_client_key.decrypt_char(&trailing),
+            expected_trailing as u8
+        );
+    }
+}
+
+#[test]
+fn strip_leading_trailing() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let ch = my_client_key.encrypt_char(b'0');
+
+    for my_string_plain in ["000042", "4200", "0000", "402", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let leading = my_server_key.strip_leading(&my_string, &ch, &public_parameters);
+        let trailing = my_server_key.strip_trailing(&my_string, &ch, &public_parameters);
+
+        assert_eq!(
+            my_client_key.decrypt(leading),
+            my_string_plain.trim_start_matches('0')
+        );
+        assert_eq!(
+            my_client_key.decrypt(trailing),
+            my_string_plain.trim_end_matches('0')
+        );
+    }
+}
+
+#[test]
+fn trim_start_matches() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (my_string_plain, pattern_plain) in [
+        ("ababzama", "ab"),
+        ("abab", "ab"),
+        ("aba", "ab"),
+        ("zamaab", "ab"),
+        ("zama", ""),
+        ("ab", "abc"),
+        ("", "ab"),
+    ] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+        let expected = my_string_plain.trim_start_matches(pattern_plain);
+
+        let res = my_server_key.trim_start_matches(&my_string, &pattern, &public_parameters);
+        assert_eq!(my_client_key.decrypt(res), expected);
+
+        let res =
+            my_server_key.trim_start_matches_clear(&my_string, pattern_plain, &public_parameters);
+        assert_eq!(my_client_key.decrypt(res), expected);
+    }
+}
+
+#[test]
+fn trim_end_matches() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (my_string_plain, pattern_plain) in [
+        ("zamaabab", "ab"),
+        ("abab", "ab"),
+        ("bab", "ab"),
+        ("abzama", "ab"),
+        ("zama", ""),
+        ("ab", "abc"),
+        ("", "ab"),
+    ] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+        let expected = my_string_plain.trim_end_matches(pattern_plain);
+
+        let res = my_server_key.trim_end_matches(&my_string, &pattern, &public_parameters);
+        assert_eq!(my_client_key.decrypt(res), expected);
+
+        let res =
+            my_server_key.trim_end_matches_clear(&my_string, pattern_plain, &public_parameters);
+        assert_eq!(my_client_key.decrypt(res), expected);
+    }
+}
+
+#[test]
+fn count_in_set() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "education";
+    let set_plain = "aeiou";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let set = my_client_key.encrypt_no_padding(set_plain);
+
+    let res = my_server_key.count_in_set(&my_string, &set, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+    let expected = my_string_plain
+        .chars()
+        .filter(|c| set_plain.contains(*c))
+        .count();
+
+    assert_eq!(dec as usize, expected);
+}
+
+#[test]
+fn checksum() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // The second string overflows a byte
+    for my_string_plain in ["zama", "zzzzzzzz", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.checksum(&my_string, &public_parameters);
+        let dec = my_client_key.decrypt_char(&res);
+
+        let expected = my_string_plain.bytes().fold(0u8, |a, b| a.wrapping_add(b));
+
+        assert_eq!(dec, expected);
+    }
+}
+
+#[test]
+fn byte_histogram() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "aaabbc";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let histogram = my_server_key.byte_histogram(&my_string, &public_parameters);
+    let dec = histogram
+        .iter()
+        .map(|count| my_client_key.decrypt_char(count))
+        .collect::<Vec<u8>>();
+
+    let mut expected = vec![0u8; 256];
+    for byte in my_string_plain.bytes() {
+        expected[byte as usize] += 1;
+    }
+
+    assert_eq!(dec, expected);
+}
+
+#[test]
+fn scalar_comparisons() {
+    let (my_client_key, my_server_key, _) = setup_test();
+    let key = &my_server_key.key;
+
+    for (plain, other) in [(b'a', b'a'), (b'a', b'b'), (b'b', b'a'), (0u8, 255u8)] {
+        let c = my_client_key.encrypt_char(plain);
+
+        let results = [
+            (c.scalar_eq(key, other), plain == other),
+            (c.scalar_ne(key, other), plain != other),
+            (c.scalar_le(key, other), plain <= other),
+            (c.scalar_lt(key, other), plain < other),
+            (c.scalar_ge(key, other), plain >= other),
+            (c.scalar_gt(key, other), plain > other),
+        ];
+
+        for (res, expected) in results {
+            assert_eq!(my_client_key.decrypt_char(&res), expected as u8);
+        }
+    }
+
+    for plain in [b' ', b'\t', b'\r', b'A', b'Z', b'a', b'z', b'0', b'@', b'['] {
+        let c = my_client_key.encrypt_char(plain);
+
+        let res = c.is_whitespace(key);
+        assert_eq!(
+            my_client_key.decrypt_char(&res),
+            plain.is_ascii_whitespace() as u8
+        );
+
+        let res = c.is_uppercase(key);
+        assert_eq!(
+            my_client_key.decrypt_char(&res),
+            plain.is_ascii_uppercase() as u8
+        );
+
+        let res = c.is_lowercase(key);
+        assert_eq!(
+            my_client_key.decrypt_char(&res),
+            plain.is_ascii_lowercase() as u8
+        );
+    }
+}
+
+#[test]
+fn most_frequent_char() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // The second string has a tie, broken by the lowest byte value
+    for (my_string_plain, expected) in [("aaabbc", (b'a', 3u8)), ("ccbb", (b'b', 2u8))] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let (most_frequent, count) =
+            my_server_key.most_frequent_char(&my_string, &public_parameters);
+
+        assert_eq!(
+            (
+                my_client_key.decrypt_char(&most_frequent),
+                my_client_key.decrypt_char(&count)
+            ),
+            expected
+        );
+    }
+}
+
+#[test]
+fn char_frequency_rank() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "aaabbcd";
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    // 'c' and 'd' are tied so neither counts for the other, 'z' is absent
+    for ch in [b'a', b'b', b'c', b'd', b'z'] {
+        let enc_ch = my_client_key.encrypt_char(ch);
+        let res = my_server_key.char_frequency_rank(&my_string, &enc_ch, &public_parameters);
+
+        let count = |c: u8| my_string_plain.bytes().filter(|&b| b == c).count();
+        let mut distinct: Vec<u8> = my_string_plain.bytes().collect();
+        distinct.sort();
+        distinct.dedup();
+        let expected = distinct.iter().filter(|&&c| count(c) < count(ch)).count();
+
+        assert_eq!(my_client_key.decrypt_char(&res), expected as u8);
+    }
+}
+
+#[test]
+fn run_length_encode() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in ["aaabb", "abca", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let runs = my_server_key.run_length_encode(&my_string, &public_parameters);
+        assert_eq!(runs.len(), my_string.len());
+
+        let actual: Vec<(u8, u8)> = runs
+            .iter()
+            .map(|(run_char, run_length)| {
+                (
+                    my_client_key.decrypt_char(run_char),
+                    my_client_key.decrypt_char(run_length),
+                )
+            })
+            .filter(|&(_, run_length)| run_length != 0)
+            .collect();
+
+        let mut expected: Vec<(u8, u8)> = Vec::new();
+        for byte in my_string_plain.bytes() {
+            match expected.last_mut() {
+                Some((run_char, run_length)) if *run_char == byte => *run_length += 1,
+                _ => expected.push((byte, 1)),
+            }
+        }
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn apply_lut() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "zama IS awesome";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let mut upper = [0u8; 256];
+    for (value, entry) in upper.iter_mut().enumerate() {
+        *entry = (value as u8).to_ascii_uppercase();
+    }
+
+    // Same result as the arithmetic version
+    let my_new_string = my_server_key.apply_lut(&my_string, &upper, &public_parameters);
+    let expected = my_server_key.to_upper(&my_string, &public_parameters);
+
+    assert_eq!(
+        my_client_key.decrypt(my_new_string),
+        my_client_key.decrypt(expected)
+    );
+
+    // The padding is preserved even if the table maps \0
+    let mut table = upper;
+    table[0] = b'x';
+    let my_new_string = my_server_key.apply_lut(&my_string, &table, &public_parameters);
+
+    assert_eq!(
+        my_client_key.decrypt(my_new_string),
+        my_string_plain.to_ascii_uppercase()
+    );
+}
+
+#[test]
+fn select() {
+    let (my_client_key, my_server_key, _) = setup_test();
+
+    let encrypt = |value: u8| my_client_key.encrypt_char(value);
+    let default = encrypt(b'd');
+
+    // The first branch whose condition is set wins
+    for (conditions, expected) in [
+        ([0u8, 0u8, 0u8], b'd'),
+        ([0, 1, 0], b'b'),
+        ([0, 1, 1], b'b'),
+        ([1, 1, 1], b'a'),
+    ] {
+        let branches = conditions
+            .iter()
+            .zip([b'a', b'b', b'c'])
+            .map(|(condition, value)| (encrypt(*condition), encrypt(value)))
+            .collect::<Vec<(FheAsciiChar, FheAsciiChar)>>();
+
+        let res = FheAsciiChar::select(&my_server_key.key, &branches, &default);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        assert_eq!(dec, expected);
+    }
+
+    let res = FheAsciiChar::select(&my_server_key.key, &[], &default);
+    assert_eq!(my_client_key.decrypt_char(&res), b'd');
+}
+
+#[test]
+fn translate() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello";
+    let from_plain = "lo";
+    let to_plain = "L0";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let from = my_client_key.encrypt_no_padding(from_plain);
+    let to = my_client_key.encrypt_no_padding(to_plain);
+
+    let translated = my_server_key.translate(&my_string, &from, &to, &public_parameters);
+
+    let actual = my_client_key.decrypt(translated);
+    let expected: String = my_string_plain
+        .chars()
+        .map(|c| match from_plain.find(c) {
+            Some(k) => to_plain.as_bytes()[k] as char,
+            None => c,
+        })
+        .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn title_from_snake_case() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (my_string_plain, expected) in [
+        ("hello_world", "Hello World"),
+        ("snake_case_id_2", "Snake Case Id 2"),
+        ("_leading__double", " Leading  Double"),
+        ("", ""),
+    ] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.title_from_snake_case(&my_string, &public_parameters);
+        let actual = my_client_key.decrypt(res);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn retain() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "a1b2c3";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let digits = my_server_key.retain(
+        &my_string,
+        |c| c.is_ascii_digit(&my_server_key.key),
+        &public_parameters,
+    );
+
+    let actual = my_client_key.decrypt(digits);
+    let mut expected = my_string_plain.to_owned();
+    expected.retain(|c| c.is_ascii_digit());
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn remove_matching() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "a b\tc";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let no_whitespace = my_server_key.remove_matching(
+        &my_string,
+        |c| c.is_whitespace(&my_server_key.key),
+        &public_parameters,
+    );
+
+    let actual = my_client_key.decrypt(no_whitespace);
+    let mut expected = my_string_plain.to_owned();
+    expected.retain(|c| !c.is_ascii_whitespace());
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn mask() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string = my_client_key.encrypt(
+        "abc",
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let fill = my_client_key.encrypt_char(b'*');
+
+    let res = my_server_key.mask(&my_string, &[true, false, true], &public_parameters);
+    assert_eq!(my_client_key.decrypt(res), "ac");
+
+    let res = my_server_key.mask_fill(&my_string, &[true, false, true], &fill, &public_parameters);
+    assert_eq!(my_client_key.decrypt(res), "a*c");
+
+    // The padding is not filled and the positions past the mask are kept
+    let res = my_server_key.mask_fill(
+        &my_string,
+        &[false, true, true, false, false],
+        &fill,
+        &public_parameters,
+    );
+    assert_eq!(my_client_key.decrypt(res), "*bc");
+
+    let res = my_server_key.mask(&my_string, &[false], &public_parameters);
+    assert_eq!(my_client_key.decrypt(res), "bc");
+}
+
+#[test]
+fn redact_digits() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let mask = my_client_key.encrypt_char(b'*');
+
+    for my_string_plain in ["id-4521", "0123-9", "no digits", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.redact_digits(&my_string, &mask, &public_parameters);
+        let actual = my_client_key.decrypt(res);
+        let expected: String = my_string_plain
+            .chars()
+            .map(|c| if c.is_ascii_digit() { '*' } else { c })
+            .collect();
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn keep_last_n() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let mask = my_client_key.encrypt_char(b'*');
+
+    for (my_string_plain, n) in [
+        ("12345678", 4),
+        ("1234", 4),
+        ("12", 4),
+        ("abc", 0),
+        ("", 2),
+        ("abc", usize::MAX),
+    ] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.keep_last_n(&my_string, n, &mask, &public_parameters);
+        let actual = my_client_key.decrypt(res);
+
+        let masked = my_string_plain.len().saturating_sub(n);
+        let expected = "*".repeat(masked) + &my_string_plain[masked..];
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn dedup() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in ["aaabccd", "abcd", "zzzz", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.dedup(&my_string, &public_parameters);
+        let actual = my_client_key.decrypt(res);
+
+        let mut expected: Vec<char> = my_string_plain.chars().collect();
+        expected.dedup();
+
+        assert_eq!(actual, expected.into_iter().collect::<String>());
+    }
+}
+
+#[test]
+fn is_ascii() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello";
+    let mut my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.is_ascii(&my_string, &public_parameters);
+    assert_eq!(my_client_key.decrypt_char(&res), 1u8);
+
+    // Simulate the output of a custom transform that left the ascii range
+    my_string[0] = FheAsciiChar::encrypt_trivial(0x80u8, &public_parameters, &my_server_key.key);
+
+    let res = my_server_key.is_ascii(&my_string, &public_parameters);
+    assert_eq!(my_client_key.decrypt_char(&res), 0u8);
+
+    let last_ascii = FheAsciiChar::encrypt_trivial(0x7Fu8, &public_parameters, &my_server_key.key);
+    let res = last_ascii.is_ascii(&my_server_key.key);
+    assert_eq!(my_client_key.decrypt_char(&res), 1u8);
+}
+
+#[test]
+fn is_sorted() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in ["abc", "acb", "aabbz", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.is_sorted(&my_string, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+        let expected = my_string_plain
+            .as_bytes()
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1]);
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn is_alphanumeric_string() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in ["user123", "user 123", "User_1", "Z9", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.is_alphanumeric_string(&my_string, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+        let expected = !my_string_plain.is_empty()
+            && my_string_plain.chars().all(|c| c.is_ascii_alphanumeric());
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn looks_like_email() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (my_string_plain, expected) in [
+        ("alice@example.com", 1u8),
+        ("a@b.c", 1),
+        ("alice.example.com", 0),
+        ("@example.com", 0),
+        ("alice@example", 0),
+        ("alice@@example.com", 0),
+        ("al.ice@example", 0),
+        ("", 0),
+    ] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.looks_like_email(&my_string, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        assert_eq!(dec, expected, "{}", my_string_plain);
+    }
+}
+
+#[test]
+fn is_numeric_string() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in ["12345", "12a45", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.is_numeric_string(&my_string, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+        let expected =
+            !my_string_plain.is_empty() && my_string_plain.chars().all(|c| c.is_ascii_digit());
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn is_not_empty() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello";
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.is_empty(&my_string, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+    let expected = my_string_plain.is_empty();
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn len() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello world";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.len(&my_string, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = my_string_plain.len();
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn rfind() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello abc abc test";
+    let needle_plain = "abc";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.rfind(heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.rfind(needle_plain).unwrap();
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn invalid_rfind() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello test";
+    let needle_plain = "abc";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.rfind(heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    // The original algoritm returns None but since we don't have this luxury we will use a
+    // placeholder value
+    let _ = heistack_plain.rfind(needle_plain);
+
+    assert_eq!(dec, MAX_FIND_LENGTH as u8);
+}
+
+#[test]
+#[should_panic(expected = "Maximum supported size for find reached")]
+fn unsupported_size_rfind() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello test".repeat(100);
+    let needle_plain = "abc";
+
+    let heistack = my_client_key.encrypt(
+        &heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let _ = my_server_key.rfind(heistack, &needle, &public_parameters);
+}
+
+#[test]
+fn find() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello test";
+    let needle_plain = "test";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.find(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.find(needle_plain).unwrap();
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn find_rk() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // "bC" has the same hash as "ab", 31 * 98 + 67 = 31 * 97 + 98 modulo 256
+    for (heistack_plain, needle_plain) in [
+        ("hello test", "test"),
+        ("abcabcab", "cab"),
+        ("no match here", "zama"),
+        ("ab", "abc"),
+        ("aaaa", ""),
+        ("xbCab", "ab"),
+        ("bC", "ab"),
+    ] {
+        let heistack = my_client_key.encrypt(
+            heistack_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+        let res = my_server_key.find_rk(&heistack, &needle, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        let expected = heistack_plain.find(needle_plain).unwrap_or(MAX_FIND_LENGTH);
+
+        assert_eq!(
+            dec, expected as u8,
+            "{:?} in {:?}",
+            needle_plain, heistack_plain
+        );
+    }
+}
+
+#[test]
+fn find_nth() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (heistack_plain, needle_plain, n) in [
+        ("a,b,c,d", ",", 2u8),
+        ("a,b,c,d", ",", 4),
+        ("a,b,c,d", ",", 0),
+        ("aaaa", "aa", 2),
+        ("abc", "", 4),
+        ("abc", "", 5),
+    ] {
+        let heistack = my_client_key.encrypt(
+            heistack_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let needle = my_client_key.encrypt_no_padding(needle_plain);
+        let enc_n = my_client_key.encrypt_char(n);
+
+        let res = my_server_key.find_nth(&heistack, &needle, &enc_n, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        let expected = (n as usize)
+            .checked_sub(1)
+            .and_then(|k| heistack_plain.match_indices(needle_plain).nth(k))
+            .map_or(MAX_FIND_LENGTH, |(i, _)| i);
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn char_at() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello world";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for i in [0u8, 6, 10, 11, 200] {
+        let index = my_client_key.encrypt_char(i);
+
+        let res = my_server_key.char_at(&my_string, &index, &public_parameters);
+        let expected = my_string_plain
+            .as_bytes()
+            .get(i as usize)
+            .copied()
+            .unwrap_or(0);
+
+        assert_eq!(my_client_key.decrypt_char(&res), expected);
+    }
+
+    // Position 256 must not be read as position 0
+    let my_string_plain = "a".to_owned() + &"b".repeat(u8::MAX as usize);
+    let my_string = my_client_key.encrypt(
+        &my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    assert!(my_string.len() > u8::MAX as usize + 1);
+
+    let index = my_client_key.encrypt_char(0);
+    let res = my_server_key.char_at(&my_string, &index, &public_parameters);
+    assert_eq!(my_client_key.decrypt_char(&res), b'a');
+}
+
+#[test]
+fn substring() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello world";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for (start, end) in [(0, 5), (6, 11), (6, 100), (3, 3), (5, 2), (20, 30)] {
+        let res = my_server_key.substring(&my_string, start, end);
+
+        let end = usize::min(end, my_string_plain.len());
+        let expected = my_string_plain.get(start..end).unwrap_or("");
+
+        assert_eq!(my_client_key.decrypt(res), expected);
+    }
+}
+
+#[test]
+fn substring_enc() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "hello world";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for (start, len) in [(6u8, 5u8), (0, 5), (3, 0), (8, 10), (20, 3)] {
+        let enc_start = my_client_key.encrypt_char(start);
+        let enc_len = my_client_key.encrypt_char(len);
+
+        let res = my_server_key.substring_enc(&my_string, &enc_start, &enc_len, &public_parameters);
+
+        // The result keeps the capacity of the input, the range is followed by padding
+        assert_eq!(res.len(), my_string.len());
+        // Decrypting drops the padding zeros
+        let actual = my_client_key.decrypt(res);
+
+        let expected: String = my_string_plain
+            .chars()
+            .skip(start as usize)
+            .take(len as usize)
+            .collect();
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn matches_count() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (heistack_plain, needle_plain) in [
+        ("zama is awesome, zama is fast", "zama"),
+        ("aaaaa", "aa"),
+        ("abc", "d"),
+        ("abc", ""),
+        ("abc", "abcd"),
+        ("", ""),
+    ] {
+        let heistack = my_client_key.encrypt(
+            heistack_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+        let expected = heistack_plain.matches(needle_plain).count() as u8;
+
+        let res = my_server_key.matches_count(&heistack, &needle, &public_parameters);
+        assert_eq!(my_client_key.decrypt_char(&res), expected);
+
+        let res = my_server_key.matches_count_clear(&heistack, needle_plain, &public_parameters);
+        assert_eq!(my_client_key.decrypt_char(&res), expected);
+    }
+}
+
+#[test]
+fn longer_needle_contains() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "abc";
+    let needle_plain = "abcd";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING + 5,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.contains(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.contains(needle_plain) as u8;
+
+    assert_eq!(dec, expected);
+}
+
+#[test]
+fn longer_needle_starts_with() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "abc";
+    let needle_plain = "abcd";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING + 5,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.starts_with(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.starts_with(needle_plain) as u8;
+
+    assert_eq!(dec, expected);
+}
+
+#[test]
+fn longer_needle_ends_with() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "abc";
+    let needle_plain = "zabc";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING + 5,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.ends_with(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.ends_with(needle_plain) as u8;
+
+    assert_eq!(dec, expected);
+}
+
+#[test]
+fn ends_with_repeated_needle() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let needle_plain = "ab";
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    for heistack_plain in ["abab", "ababa", "b", "ab", ""] {
+        let heistack = my_client_key.encrypt(
+            heistack_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.ends_with(&heistack, &needle, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        assert_eq!(dec, heistack_plain.ends_with(needle_plain) as u8);
+    }
+}
+
+#[test]
+fn longer_needle_find() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "abc";
+    let needle_plain = "abcdefgh";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.find(&heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain.find(needle_plain).unwrap_or(MAX_FIND_LENGTH) as u8;
+
+    assert_eq!(dec, expected);
+}
+
+#[test]
+fn longer_needle_rfind() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "abc";
+    let needle_plain = "abcdefgh";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let res = my_server_key.rfind(heistack, &needle, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = heistack_plain
+        .rfind(needle_plain)
+        .unwrap_or(MAX_FIND_LENGTH) as u8;
+
+    assert_eq!(dec, expected);
+}
+
+#[test]
+fn longer_needle_strip_prefix() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "abc";
+    let pattern_plain = "abcdefgh";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_strip = my_server_key.strip_prefix(&my_string, &pattern, &public_parameters);
+    let (actual, flag) = FheStrip::decrypt(fhe_strip, &my_client_key);
+
+    let expected = my_string_plain.strip_prefix(pattern_plain);
+
+    assert_eq!(actual, my_string_plain);
+    assert_eq!(flag, expected.is_some() as u8);
+}
+
+#[test]
+fn longer_needle_strip_suffix() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "abc";
+    let pattern_plain = "zzabc";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_strip = my_server_key.strip_suffix(my_string, &pattern, &public_parameters);
+    let (actual, flag) = FheStrip::decrypt(fhe_strip, &my_client_key);
+
+    let expected = my_string_plain.strip_suffix(pattern_plain);
+
+    assert_eq!(actual, my_string_plain);
+    assert_eq!(flag, expected.is_some() as u8);
+}
+
+#[test]
+fn contains_clear_opts() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "Hello";
+    let needle_plain = "hello";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for ignore_case in [false, true] {
+        let res = my_server_key.contains_clear_opts(
+            &heistack,
+            needle_plain,
+            ignore_case,
+            &public_parameters,
+        );
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        let expected = if ignore_case {
+            heistack_plain
+                .to_ascii_lowercase()
+                .contains(&needle_plain.to_ascii_lowercase())
+        } else {
+            heistack_plain.contains(needle_plain)
+        };
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn contains_with_position() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "hello test test";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for needle_plain in ["test", "hello", "zama", ""] {
+        let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+        let (found, position) =
+            my_server_key.contains_with_position(&heistack, &needle, &public_parameters);
+        let found: u8 = my_client_key.decrypt_char(&found);
+        let position: u8 = my_client_key.decrypt_char(&position);
+
+        let expected_found = heistack_plain.contains(needle_plain);
+        let expected_position = heistack_plain.find(needle_plain).unwrap_or(MAX_FIND_LENGTH);
+
+        assert_eq!(found, expected_found as u8);
+        assert_eq!(position, expected_position as u8);
+    }
+}
+
+#[test]
+fn eq() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack1_plain = "hello test";
+    let heistack2_plain = "hello test";
+
+    let heistack1 = my_client_key.encrypt(
+        heistack1_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let heistack2 = my_client_key.encrypt(
+        heistack2_plain,
+        STRING_PADDING + 20,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.eq(&heistack1, &heistack2, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+    let expected = heistack1_plain.eq(heistack2_plain);
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn equals_any() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let allowed_plain = ["todo", "doing", "done"];
+    let allowed = allowed_plain
+        .iter()
+        .map(|s| my_client_key.encrypt_auto(s, &public_parameters, &my_server_key.key))
+        .collect::<Vec<FheString>>();
+
+    for status_plain in ["done", "todo", "do", "dones", ""] {
+        let status = my_client_key.encrypt(
+            status_plain,
+            STRING_PADDING + 2,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.equals_any(&status, &allowed, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+        assert_eq!(dec, allowed_plain.contains(&status_plain) as u8);
+
+        let res = my_server_key.which_equals(&status, &allowed, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+        let expected = allowed_plain
+            .iter()
+            .position(|s| *s == status_plain)
+            .unwrap_or(MAX_FIND_LENGTH);
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn diff_positions() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack1_plain = "kitten";
+    let heistack2_plain = "sitten";
+
+    let heistack1 = my_client_key.encrypt(
+        heistack1_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let heistack2 = my_client_key.encrypt(
+        heistack2_plain,
+        STRING_PADDING + 2,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.diff_positions(&heistack1, &heistack2);
+    let dec = res
+        .iter()
+        .map(|c| my_client_key.decrypt_char(c))
+        .collect::<Vec<u8>>();
+
+    let expected = heistack1_plain
+        .bytes()
+        .zip(heistack2_plain.bytes())
+        .map(|(a, b)| (a != b) as u8)
+        .chain([0u8; STRING_PADDING])
+        .collect::<Vec<u8>>();
+
+    assert_eq!(dec.len(), usize::min(heistack1.len(), heistack2.len()));
+    assert_eq!(dec, expected);
+}
+
+#[test]
+fn common_prefix_len() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (heistack1_plain, heistack2_plain) in [("abcX", "abcY"), ("abc", "abc"), ("ab", "x")] {
+        let heistack1 = my_client_key.encrypt(
+            heistack1_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let heistack2 = my_client_key.encrypt(
+            heistack2_plain,
+            STRING_PADDING + 2,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.common_prefix_len(&heistack1, &heistack2, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        let expected = heistack1_plain
+            .bytes()
+            .zip(heistack2_plain.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn common_suffix_len() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (heistack1_plain, heistack2_plain) in [("Xyz", "Wyz"), ("xyz", "yz"), ("ab", "x")] {
+        let heistack1 = my_client_key.encrypt(
+            heistack1_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let heistack2 = my_client_key.encrypt(
+            heistack2_plain,
+            STRING_PADDING + 2,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.common_suffix_len(&heistack1, &heistack2, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+
+        let expected = heistack1_plain
+            .bytes()
+            .rev()
+            .zip(heistack2_plain.bytes().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn eq_ignore_case() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack1_plain = "hello TEST";
+    let heistack2_plain = "hello test";
+
+    let heistack1 = my_client_key.encrypt(
+        heistack1_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let heistack2 = my_client_key.encrypt(
+        heistack2_plain,
+        STRING_PADDING + 20,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.eq_ignore_case(&heistack1, &heistack2, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+    let expected = heistack1_plain.eq_ignore_ascii_case(heistack2_plain);
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn eq_ignore_case_clear() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack_plain = "Hello";
+
+    let heistack = my_client_key.encrypt(
+        heistack_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for other_plain in ["hello", "HELLO", "help", "hello!"] {
+        let res = my_server_key.eq_ignore_case_clear(&heistack, other_plain, &public_parameters);
+        let dec: u8 = my_client_key.decrypt_char(&res);
+        let expected = heistack_plain.eq_ignore_ascii_case(other_plain);
+
+        assert_eq!(dec, expected as u8);
+    }
+}
+
+#[test]
+fn eq_trimmed() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let heistack1_plain = "  hi ";
+    let heistack2_plain = "hi";
+
+    let heistack1 = my_client_key.encrypt(
+        heistack1_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let heistack2 = my_client_key.encrypt(
+        heistack2_plain,
+        STRING_PADDING + 3,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let res = my_server_key.eq_trimmed(&heistack1, &heistack2, &public_parameters);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+    let expected = heistack1_plain.trim() == heistack2_plain.trim();
+
+    assert_eq!(dec, expected as u8);
+}
+
+#[test]
+fn strip_prefix() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "HELLO test test HELLO";
+    let pattern_plain = "HELLO";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+    let fhe_strip = my_server_key.strip_prefix(&my_string, &pattern, &public_parameters);
+
+    let (actual, _) = FheStrip::decrypt(fhe_strip, &my_client_key);
+
+    let expected = my_string_plain.strip_prefix(pattern_plain).unwrap();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn strip_suffix() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "HELLO test test HELLO";
+    let pattern_plain = "HELLO";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_strip = my_server_key.strip_suffix(my_string, &pattern, &public_parameters);
+
+    let (actual, _) = FheStrip::decrypt(fhe_strip, &my_client_key);
+
+    let expected = my_string_plain.strip_suffix(pattern_plain).unwrap();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn dont_strip_suffix() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "HELLO test test HELLO";
+    let pattern_plain = "WORLD";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_strip = my_server_key.strip_suffix(my_string, &pattern, &public_parameters);
+
+    let (_, pattern_found) = FheStrip::decrypt(fhe_strip, &my_client_key);
+
+    // This is None but in our case the string is not modified
+    let expected = my_string_plain.strip_suffix(pattern_plain);
+
+    let expected_pattern_found = if let Some(_) = expected { true } else { false };
+
+    assert_eq!(pattern_found, expected_pattern_found as u8);
+}
+
+#[test]
+fn dont_strip_prefix() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "HELLO test test HELLO";
+    let pattern_plain = "WORLD";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt(pattern_plain, 0, &public_parameters, &my_server_key.key);
+    let fhe_strip =
+        my_server_key.strip_prefix(&my_string, &pattern.get_bytes(), &public_parameters);
+
+    let (_, pattern_found) = FheStrip::decrypt(fhe_strip, &my_client_key);
+
+    // This is None but in our case the string is not modified
+    let expected = my_string_plain.strip_prefix(pattern_plain);
+
+    let expected_pattern_found = if let Some(_) = expected { true } else { false };
+
+    assert_eq!(pattern_found, expected_pattern_found as u8);
+}
+
+#[test]
+fn concatenate() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string1_plain = "Hello, ";
+    let my_string2_plain = "World!";
+
+    let my_string1 = my_client_key.encrypt(
+        my_string1_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string2 = my_client_key.encrypt(
+        my_string2_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string_upper = my_server_key.concatenate(&my_string1, &my_string2, &public_parameters);
+
+    let actual = my_client_key.decrypt(my_string_upper);
+    assert_eq!(actual, format!("{}{}", my_string1_plain, my_string2_plain));
+}
+
+#[test]
+fn concatenate_long_first_string() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // The padding of the first string does not fit in an encrypted character
+    let my_string1_plain = "Hello, ";
+    let my_string2_plain = "World!";
+
+    let my_string1 = my_client_key.encrypt(
+        my_string1_plain,
+        260,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let my_string2 = my_client_key.encrypt(
+        my_string2_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    assert!(my_string1.len() > u8::MAX as usize);
+
+    let result = my_server_key.concatenate(&my_string1, &my_string2, &public_parameters);
+
+    let actual = my_client_key.decrypt(result);
+    assert_eq!(actual, format!("{}{}", my_string1_plain, my_string2_plain));
+}
+
+#[test]
+fn interleave() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for (my_string1_plain, my_string2_plain) in
+        [("ace", "bdf"), ("abcd", "x"), ("x", "abcd"), ("", "ab")]
+    {
+        let my_string1 = my_client_key.encrypt(
+            my_string1_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let my_string2 = my_client_key.encrypt(
+            my_string2_plain,
+            STRING_PADDING + 3,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.interleave(&my_string1, &my_string2, &public_parameters);
+        let actual = my_client_key.decrypt(res);
+
+        let mut expected = String::new();
+        let mut chars1 = my_string1_plain.chars();
+        let mut chars2 = my_string2_plain.chars();
+        for _ in 0..usize::max(my_string1_plain.len(), my_string2_plain.len()) {
+            expected.extend(chars1.next());
+            expected.extend(chars2.next());
+        }
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn deinterleave() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in ["abcdef", "abcde", "a", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let (even, odd) = my_server_key.deinterleave(&my_string, &public_parameters);
+
+        let expected_even: String = my_string_plain.chars().step_by(2).collect();
+        let expected_odd: String = my_string_plain.chars().skip(1).step_by(2).collect();
+
+        // Round trip through interleave
+        let res = my_server_key.interleave(&even, &odd, &public_parameters);
+        assert_eq!(my_client_key.decrypt(res), my_string_plain);
+
+        assert_eq!(my_client_key.decrypt(even), expected_even);
+        assert_eq!(my_client_key.decrypt(odd), expected_odd);
+    }
+}
+
+#[test]
+fn rotate() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // Shifts of the length or more wrap around
+    for (my_string_plain, k) in [
+        ("abcde", 2u8),
+        ("abcde", 0),
+        ("abcde", 7),
+        ("ab", 200),
+        ("", 3),
+    ] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let enc_k = my_client_key.encrypt_char(k);
+
+        let left = my_server_key.rotate_left(&my_string, &enc_k, &public_parameters);
+        let right = my_server_key.rotate_right(&my_string, &enc_k, &public_parameters);
+
+        let mut expected_left = my_string_plain.as_bytes().to_vec();
+        let mut expected_right = expected_left.clone();
+        if !my_string_plain.is_empty() {
+            expected_left.rotate_left(k as usize % my_string_plain.len());
+            expected_right.rotate_right(k as usize % my_string_plain.len());
+        }
+
+        assert_eq!(my_client_key.decrypt(left).into_bytes(), expected_left);
+        assert_eq!(my_client_key.decrypt(right).into_bytes(), expected_right);
+    }
+}
+
+#[test]
+fn concatenate_chained() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_strings_plain = ["Hello", ", ", "", "World!"];
+
+    let my_strings: Vec<FheString> = my_strings_plain
+        .iter()
+        .map(|my_string_plain| {
+            my_client_key.encrypt(
+                my_string_plain,
+                STRING_PADDING,
+                &public_parameters,
+                &my_server_key.key,
+            )
+        })
+        .collect();
+
+    let mut result = my_strings[0].clone();
+    for my_string in &my_strings[1..] {
+        result = my_server_key.concatenate(&result, my_string, &public_parameters);
+    }
+
+    let actual = my_client_key.decrypt(result);
+    assert_eq!(actual, my_strings_plain.concat());
+}
+
+#[test]
+fn add_operator() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string1_plain = "Hello, ";
+    let my_string2_plain = "World";
+    let my_string3_plain = "!";
+
+    let [my_string1, my_string2, my_string3] =
+        [my_string1_plain, my_string2_plain, my_string3_plain].map(|plain| {
+            let my_string = my_client_key.encrypt(
+                plain,
+                STRING_PADDING,
+                &public_parameters,
+                &my_server_key.key,
+            );
+            StringOps::new(my_string, &my_server_key, &public_parameters)
+        });
+
+    let my_string_concatenated = &(&my_string1 + &my_string2) + &my_string3;
+
+    let actual = my_client_key.decrypt(my_string_concatenated.into_inner());
+    assert_eq!(
+        actual,
+        format!(
+            "{}{}{}",
+            my_string1_plain, my_string2_plain, my_string3_plain
+        )
+    );
+}
+
+#[test]
+fn context_wrapper() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+    let ctx = FheStringOps::new(&my_server_key, &public_parameters);
+
+    let my_string_plain = "  Hello, World!  ";
+    let needle_plain = "WORLD";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let needle = my_client_key.encrypt_no_padding(needle_plain);
+
+    let my_string_upper = ctx.to_upper(&ctx.trim(&my_string));
+    let res = ctx.contains(&my_string_upper, &needle);
+    let dec: u8 = my_client_key.decrypt_char(&res);
+
+    let expected = my_string_plain.trim().to_uppercase().contains(needle_plain);
+
+    assert_eq!(dec, expected as u8);
+
+    let my_string_concatenated = ctx.wrap(my_string_upper) + ctx.wrap(my_string);
+    let actual = my_client_key.decrypt(my_string_concatenated.into_inner());
+
+    assert_eq!(
+        actual,
+        format!(
+            "{}{}",
+            my_string_plain.trim().to_uppercase(),
+            my_string_plain
+        )
+    );
+}
+
+#[test]
+fn less_than() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain1 = "aaa";
+    let my_string_plain2 = "aaaa";
+
+    let heistack1 = my_client_key.encrypt(
+        my_string_plain1,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let heistack2 = my_client_key.encrypt(
+        my_string_plain2,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let actual = my_server_key.lt(&heistack1, &heistack2, &public_parameters);
+
+    let deccrypted_actual: u8 = my_client_key.decrypt_char(&actual);
+
+    let expected = (my_string_plain1 < my_string_plain2) as u8;
+
+    assert_eq!(expected, deccrypted_actual);
+}
+
+#[test]
+fn less_equal() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain1 = "aaa";
+    let my_string_plain2 = "aaaa";
+
+    let heistack1 = my_client_key.encrypt(
+        my_string_plain1,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let heistack2 = my_client_key.encrypt(
+        my_string_plain2,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let actual = my_server_key.le(&heistack1, &heistack2, &public_parameters);
+
+    let deccrypted_actual: u8 = my_client_key.decrypt_char(&actual);
+
+    let expected = (my_string_plain1 <= my_string_plain2) as u8;
+
+    assert_eq!(expected, deccrypted_actual);
+}
+
+#[test]
+fn greater_than() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain1 = "aaa";
+    let my_string_plain2 = "aaaa";
+
+    let heistack1 = my_client_key.encrypt(
+        my_string_plain1,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let heistack2 = my_client_key.encrypt(
+        my_string_plain2,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let actual = my_server_key.gt(&heistack1, &heistack2, &public_parameters);
+
+    let deccrypted_actual: u8 = my_client_key.decrypt_char(&actual);
+
+    let expected = (my_string_plain1 > my_string_plain2) as u8;
+
+    assert_eq!(expected, deccrypted_actual);
+}
+
+#[test]
+fn greater_equal() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain1 = "aaa";
+    let my_string_plain2 = "aaaa";
+
+    let heistack1 = my_client_key.encrypt(
+        my_string_plain1,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let heistack2 = my_client_key.encrypt(
+        my_string_plain2,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let actual = my_server_key.ge(&heistack1, &heistack2, &public_parameters);
+
+    let deccrypted_actual: u8 = my_client_key.decrypt_char(&actual);
+
+    let expected = (my_string_plain1 >= my_string_plain2) as u8;
+
+    assert_eq!(expected, deccrypted_actual);
+}
+
+#[test]
+fn comparison_with_different_capacities() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    // The padding has to sort before every character, whichever string has more of it
+    for (my_string_plain1, padding1, my_string_plain2, padding2) in [
+        ("ab", 1, "abc", 27),
+        ("ab", 28, "abc", 0),
+        ("abc", 0, "ab", 28),
+        ("ab", 1, "ab", 28),
+        ("", 0, "a", 29),
+        ("", 30, "a", 0),
+    ] {
+        let heistack1 = my_client_key.encrypt(
+            my_string_plain1,
+            padding1,
+            &public_parameters,
+            &my_server_key.key,
+        );
+        let heistack2 = my_client_key.encrypt(
+            my_string_plain2,
+            padding2,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let actual = [
+            my_server_key.lt(&heistack1, &heistack2, &public_parameters),
+            my_server_key.le(&heistack1, &heistack2, &public_parameters),
+            my_server_key.gt(&heistack1, &heistack2, &public_parameters),
+            my_server_key.ge(&heistack1, &heistack2, &public_parameters),
+        ]
+        .map(|res| my_client_key.decrypt_char(&res));
+
+        let expected = [
+            my_string_plain1 < my_string_plain2,
+            my_string_plain1 <= my_string_plain2,
+            my_string_plain1 > my_string_plain2,
+            my_string_plain1 >= my_string_plain2,
+        ]
+        .map(|res| res as u8);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn split() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = " Mary had a";
+    let pattern_plain = " ";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.split(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain.split(pattern_plain).collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn split_with_positions() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "a.b.c";
+    let pattern_plain = ".";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let (fhe_split, positions) =
+        my_server_key.split_with_positions(&my_string, &pattern, &public_parameters);
+    let (plain_split, _) = FheSplit::decrypt(fhe_split, &my_client_key);
+    let plain_positions = positions
+        .iter()
+        .map(|position| my_client_key.decrypt_char(position) as usize)
+        .filter(|position| *position != MAX_FIND_LENGTH)
+        .collect::<Vec<usize>>();
+
+    let expected: Vec<&str> = my_string_plain.split(pattern_plain).collect();
+    let expected_positions: Vec<usize> = my_string_plain
+        .match_indices(pattern_plain)
+        .map(|(position, _)| position)
+        .collect();
+
+    assert_eq!(trim_vector(plain_split), expected);
+    assert_eq!(plain_positions, expected_positions);
+}
+
+#[test]
+fn split_into_fhe_strings() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "Mary had a";
+    let pattern_plain = " ";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.split(&my_string, &pattern, &public_parameters);
+    let plain_split = fhe_split
+        .into_fhe_strings()
+        .iter()
+        .map(|token| {
+            let token_upper = my_server_key.to_upper(token, &public_parameters);
+            my_client_key.decrypt(token_upper)
+        })
+        .collect::<Vec<String>>();
+    let expected: Vec<String> = my_string_plain
+        .split(pattern_plain)
+        .map(|token| token.to_uppercase())
+        .collect();
+
+    let plain_split = trim_vector(plain_split);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn split_rejoin() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let pattern_plain = ",";
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    for my_string_plain in ["a,b", "a,,b", ",a", "a,", "ab,cd,,efg,hij,k,"] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let fhe_split = my_server_key.split(&my_string, &pattern, &public_parameters);
+        let rejoined = fhe_split.rejoin(&pattern, &my_server_key, &public_parameters);
+
+        // The trailing empty parts are dropped
+        let expected = my_string_plain.trim_end_matches(pattern_plain);
+
+        assert_eq!(my_client_key.decrypt(rejoined), expected);
+    }
+}
+
+#[test]
+fn split_map() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = " a , b,c ,, d ";
+    let pattern_plain = ",";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key
+        .split(&my_string, &pattern, &public_parameters)
+        .map(|token| my_server_key.trim(token, &public_parameters));
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain
+        .split(pattern_plain)
+        .map(|token| token.trim())
+        .collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn split_empty_pattern() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "abc";
+    let pattern_plain = "";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.split(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain.split(pattern_plain).collect();
+
+    // The remaining buffers are empty
+    assert_eq!(&plain_split.0[..expected.len()], expected.as_slice());
+    assert!(plain_split.0[expected.len()..].iter().all(|s| s.is_empty()));
+}
+
+#[test]
+fn rsplit_empty_pattern() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "abc";
+    let pattern_plain = "";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.rsplit(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain.rsplit(pattern_plain).collect();
+
+    // The remaining buffers are empty
+    assert_eq!(&plain_split.0[..expected.len()], expected.as_slice());
+    assert!(plain_split.0[expected.len()..].iter().all(|s| s.is_empty()));
+}
+
+#[test]
+fn split_inclusive() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "Mary had a";
+    let pattern_plain = " ";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.split_inclusive(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain.split_inclusive(pattern_plain).collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn split_terminator() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.";
+    let pattern_plain = ".";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.split_terminator(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain.split_terminator(pattern_plain).collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn split_ascii_whitespace() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = " A\nB\t";
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let fhe_split = my_server_key.split_ascii_whitespace(&my_string, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain.split_ascii_whitespace().collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn reverse_words() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    for my_string_plain in [
+        "the quick brown",
+        "the quick  brown",
+        "  a \tb  c ",
+        "word",
+        "",
+    ] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let res = my_server_key.reverse_words(&my_string, &public_parameters);
+        let actual = my_client_key.decrypt(res);
+        let expected = my_string_plain
+            .split_ascii_whitespace()
+            .rev()
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn split_bounded() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "a,b,c,d";
+    let pattern_plain = ",";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    for max_segments in [1, 2, 4] {
+        let fhe_split =
+            my_server_key.split_bounded(&my_string, &pattern, max_segments, &public_parameters);
+        let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+        let expected: Vec<&str> = my_string_plain
+            .splitn(max_segments, pattern_plain)
+            .collect();
+
+        assert_eq!(plain_split.0.len(), max_segments);
+        assert_eq!(plain_split.0, expected);
+    }
+}
+
+#[test]
+fn split_once() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "a.b.c";
+    let pattern_plain = ".";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let expected_tuple = my_string_plain.split_once(pattern_plain).unwrap();
+    let expected = vec![expected_tuple.0, expected_tuple.1];
+
+    let fhe_split = my_server_key.split_once(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    assert_eq!(plain_split.1, 1u8);
+    assert_eq!(plain_split.0, expected);
+
+    let fhe_split = my_server_key.split_once_clear(&my_string, pattern_plain, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    assert_eq!(plain_split.1, 1u8);
+    assert_eq!(plain_split.0, expected);
+}
+
+#[test]
+fn split_once_not_found() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "a.b.c";
+    let pattern_plain = "x";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let fhe_split = my_server_key.split_once_clear(&my_string, pattern_plain, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+    // str::split_once returns None, which is encoded as an unset pattern_found flag
+    assert!(my_string_plain.split_once(pattern_plain).is_none());
+    assert_eq!(plain_split.1, 0u8);
+    assert_eq!(plain_split.0, vec![my_string_plain, ""]);
+}
+
+#[test]
+fn truncate_at_char() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let delim = my_client_key.encrypt_char(b'=');
+
+    for my_string_plain in ["key=value", "key", "=value", "a=b=c", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let head = my_server_key.truncate_at_char(&my_string, &delim, &public_parameters);
+        let expected = my_string_plain.split('=').next().unwrap();
+
+        assert_eq!(my_client_key.decrypt(head), expected);
+    }
+}
+
+#[test]
+fn after_char() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let delim = my_client_key.encrypt_char(b'=');
+
+    for my_string_plain in ["key=value", "key", "key=", "a=b=c", ""] {
+        let my_string = my_client_key.encrypt(
+            my_string_plain,
+            STRING_PADDING,
+            &public_parameters,
+            &my_server_key.key,
+        );
+
+        let fhe_strip = my_server_key.after_char(&my_string, &delim, &public_parameters);
+        let (actual, found) = FheStrip::decrypt(fhe_strip, &my_client_key);
+
+        let expected = my_string_plain.split_once('=').map(|(_, tail)| tail);
+
+        assert_eq!(actual, expected.unwrap_or(""));
+        assert_eq!(found, expected.is_some() as u8);
+    }
+}
+
+#[test]
+fn splitn() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.C.";
+    let pattern_plain = ".";
+    let n_plain = 2u8;
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+    let n = FheAsciiChar::encrypt_trivial(n_plain, &public_parameters, &my_server_key.key);
+
+    let fhe_split = my_server_key.splitn(&my_string, &pattern, n, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+    let expected: Vec<&str> = my_string_plain
+        .splitn(n_plain.into(), pattern_plain)
+        .collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn splitn_clear_matches_splitn() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.C.";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for pattern_plain in [".", ""] {
+        let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+        for n_plain in [0u8, 1, 2, 3, 20] {
+            let n = FheAsciiChar::encrypt_trivial(n_plain, &public_parameters, &my_server_key.key);
+
+            let fhe_split = my_server_key.splitn(&my_string, &pattern, n, &public_parameters);
+            let encrypted_n_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+            let fhe_split = my_server_key.splitn_clear(
+                &my_string,
+                pattern_plain,
+                n_plain.into(),
+                &public_parameters,
+            );
+            let clear_n_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+            // Only the number of trailing empty buffers differs
+            assert!(clear_n_split.0.len() <= usize::from(n_plain));
+            assert_eq!(clear_n_split.1, encrypted_n_split.1);
+            assert_eq!(
+                trim_vector(clear_n_split.0),
+                trim_vector(encrypted_n_split.0)
+            );
+        }
+    }
+}
+
+#[test]
+fn splitn_larger_than_occurrences() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.";
+    let n_plain = 10u8;
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    for pattern_plain in [".", ""] {
+        let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+        let n = FheAsciiChar::encrypt_trivial(n_plain, &public_parameters, &my_server_key.key);
+
+        let fhe_split = my_server_key.splitn(&my_string, &pattern, n, &public_parameters);
+        let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+        let expected: Vec<&str> = my_string_plain
+            .splitn(n_plain.into(), pattern_plain)
+            .collect();
+        assert_eq!(
+            expected,
+            my_string_plain.split(pattern_plain).collect::<Vec<&str>>()
+        );
+
+        // The remaining buffers are empty
+        assert_eq!(&plain_split.0[..expected.len()], expected.as_slice());
+        assert!(plain_split.0[expected.len()..].iter().all(|s| s.is_empty()));
+    }
+}
+
+#[test]
+fn rsplit() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.C.";
+    let pattern_plain = ".";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.rsplit(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain.rsplit(pattern_plain).collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn rsplit_once() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.C.";
+    let pattern_plain = ".";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.rsplit_once(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected_tuple = my_string_plain.rsplit_once(pattern_plain).unwrap();
+    let expected = vec![expected_tuple.1, expected_tuple.0];
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn rsplit_once_not_found() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.C.";
+    let pattern_plain = "x";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+
+    let fhe_split = my_server_key.rsplit_once_clear(&my_string, pattern_plain, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+    // str::rsplit_once returns None, which is encoded as an unset pattern_found flag
+    assert!(my_string_plain.rsplit_once(pattern_plain).is_none());
+    assert_eq!(plain_split.1, 0u8);
+    assert_eq!(plain_split.0[0], my_string_plain);
+    assert!(plain_split.0[1..].iter().all(|part| part.is_empty()));
+}
+
+#[test]
+fn rsplit_order() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.C.";
+    let pattern_plain = ".";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    // rsplit yields the parts in the same order as str::rsplit
+    let fhe_split = my_server_key.rsplit(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let expected: Vec<&str> = my_string_plain.rsplit(pattern_plain).collect();
+
+    assert_eq!(&plain_split.0[..expected.len()], expected.as_slice());
+
+    // rsplit_once yields (after, before), the reverse of str::rsplit_once
+    let fhe_split = my_server_key.rsplit_once(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+    let (before, after) = my_string_plain.rsplit_once(pattern_plain).unwrap();
+
+    assert_eq!(&plain_split.0[..2], &[after, before]);
+    assert_eq!(plain_split.1, 1u8);
+}
+
+#[test]
+fn rsplitn() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = ".A.B.C.";
+    let pattern_plain = ".";
+    let n_plain = 3u8;
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+    let n = FheAsciiChar::encrypt_trivial(n_plain, &public_parameters, &my_server_key.key);
+
+    let fhe_split = my_server_key.rsplitn(&my_string, &pattern, n, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+    let expected: Vec<&str> = my_string_plain
+        .rsplitn(n_plain.into(), pattern_plain)
+        .collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn rsplit_terminator() {
+    let (my_client_key, my_server_key, public_parameters) = setup_test();
+
+    let my_string_plain = "....A.B.C.";
+    let pattern_plain = ".";
+
+    let my_string = my_client_key.encrypt(
+        my_string_plain,
+        STRING_PADDING,
+        &public_parameters,
+        &my_server_key.key,
+    );
+    let pattern = my_client_key.encrypt_no_padding(pattern_plain);
+
+    let fhe_split = my_server_key.rsplit_terminator(&my_string, &pattern, &public_parameters);
+    let plain_split = FheSplit::decrypt(fhe_split, &my_client_key);
+
+    let expected: Vec<&str> = my_string_plain.rsplit_terminator(pattern_plain).collect();
+
+    let plain_split = trim_vector(plain_split.0);
+    let expected = trim_str_vector(expected);
+    assert_eq!(plain_split, expected);
+}
+
+#[test]
+fn ffi_round_trip() {
+    use crate::ffi::*;
+    use std::ffi::{CStr, CString};
+    use std::ptr;
+
+    unsafe {
+        let client_key = fhestring_client_key_new();
+        let server_key = fhestring_server_key_new(client_key);
+        assert!(!client_key.is_null() && !server_key.is_null());
+
+        let encrypt = |plain: &str, padding| {
+            let plain = CString::new(plain).unwrap();
+            fhestring_encrypt(client_key, plain.as_ptr(), padding)
+        };
+        let string = encrypt("hello test", STRING_PADDING);
+        let pattern = encrypt("test", 0);
+        let to = encrypt("zama", 0);
+
+        // Invalid input is reported instead of unwinding
+        assert!(encrypt("café", 0).is_null());
+        assert!(fhestring_contains(server_key, string, ptr::null()).is_null());
+        assert!(fhestring_server_key_new(ptr::null()).is_null());
+
+        let mut plain = 0;
+        let contains = fhestring_contains(server_key, string, pattern);
+        assert_eq!(fhestring_decrypt_char(client_key, contains, &mut plain), 0);
+        assert_eq!(plain, 1);
+        assert_eq!(
+            fhestring_decrypt_char(client_key, contains, ptr::null_mut()),
+            -1
+        );
+
+        let position = fhestring_find(server_key, string, pattern);
+        assert_eq!(fhestring_decrypt_char(client_key, position, &mut plain), 0);
+        assert_eq!(plain, 6);
+
+        let replaced = fhestring_replace(server_key, string, pattern, to);
+        let decrypted = fhestring_decrypt(client_key, replaced);
+        assert_eq!(CStr::from_ptr(decrypted).to_str(), Ok("hello zama"));
+
+        fhestring_c_string_free(decrypted);
+        for string in [replaced, to, pattern, string] {
+            fhestring_string_free(string);
+        }
+        fhestring_char_free(position);
+        fhestring_char_free(contains);
+        fhestring_server_key_free(server_key);
+        fhestring_client_key_free(client_key);
+    }
+}
@@ -0,0 +1,110 @@
+// Browser bindings of the client side, compiled with the `wasm` feature for the
+// `wasm32-unknown-unknown` target. The page generates the keys, encrypts and decrypts the
+// strings, and exports the server key, the public parameters and the encrypted strings as bytes
+// for a native server to process, see examples/wasm.
+//
+// The default tfhe seeder reads from the OS, which the browser does not expose. The `wasm`
+// feature enables the js api of tfhe, which seeds from `crypto.getRandomValues` through getrandom
+// and uses the software CSPRNG instead of the one based on the AES instructions.
+
+use crate::ciphertext::envelope::parameters_fingerprint;
+use crate::{FheString, MyClientKey, MyServerKey, PublicParameters, MAX_BLOCKS};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct WasmClientKey {
+    inner: MyClientKey,
+    // Every encryption needs them, derive them once instead of cloning them out of the key
+    server_key: MyServerKey,
+    public_parameters: PublicParameters,
+    // Fingerprint of the public parameters, written in every envelope
+    fingerprint: u64,
+}
+
+#[wasm_bindgen]
+impl WasmClientKey {
+    /// Generates new keys with the default parameter set.
+    #[wasm_bindgen(constructor)]
+    pub fn generate() -> WasmClientKey {
+        WasmClientKey::from_key(MyClientKey::default_secure(MAX_BLOCKS))
+    }
+
+    /// Restores keys written by `serialize`.
+    pub fn deserialize(bytes: &[u8]) -> Result<WasmClientKey, JsError> {
+        let inner = bincode::deserialize(bytes)?;
+        Ok(WasmClientKey::from_key(inner))
+    }
+
+    /// Serializes the keys, including the private client key, to store them on the client.
+    pub fn serialize(&self) -> Result<Vec<u8>, JsError> {
+        Ok(bincode::serialize(&self.inner)?)
+    }
+
+    /// Serializes the `MyServerKey` to send to the server.
+    pub fn server_key(&self) -> Result<Vec<u8>, JsError> {
+        Ok(bincode::serialize(&self.server_key)?)
+    }
+
+    /// Serializes the `PublicParameters` to send to the server.
+    pub fn public_parameters(&self) -> Result<Vec<u8>, JsError> {
+        Ok(bincode::serialize(&self.public_parameters)?)
+    }
+
+    /// Encrypts an ascii string followed by `padding` \0 characters.
+    pub fn encrypt(&self, string: &str, padding: usize) -> Result<WasmFheString, JsError> {
+        // Reported to the page instead of the panic of `MyClientKey::encrypt`
+        if !string.bytes().all(|byte| byte.is_ascii() && byte != 0) {
+            return Err(JsError::new(
+                "The input string must only contain ascii letters and not include null characters",
+            ));
+        }
+
+        let inner = self.inner.encrypt(
+            string,
+            padding,
+            &self.public_parameters,
+            &self.server_key.key,
+        );
+        Ok(WasmFheString { inner })
+    }
+
+    /// Decrypts a string, or fails if one of its characters is not ascii.
+    pub fn decrypt(&self, string: &WasmFheString) -> Result<String, JsError> {
+        self.inner
+            .decrypt_checked(&string.inner)
+            .map_err(|_| JsError::new("The decrypted string is not ASCII"))
+    }
+}
+
+impl WasmClientKey {
+    fn from_key(inner: MyClientKey) -> WasmClientKey {
+        let public_parameters = inner.get_public_parameters();
+
+        WasmClientKey {
+            server_key: inner.get_server_key(),
+            fingerprint: parameters_fingerprint(&public_parameters),
+            public_parameters,
+            inner,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub struct WasmFheString {
+    inner: FheString,
+}
+
+#[wasm_bindgen]
+impl WasmFheString {
+    /// Serializes the string in an envelope bound to the public parameters of `key`, which the
+    /// server loads with `FheString::from_envelope`.
+    pub fn to_envelope(&self, key: &WasmClientKey) -> Vec<u8> {
+        self.inner.to_envelope_with_fingerprint(key.fingerprint)
+    }
+
+    /// Loads a string from an envelope, such as one written back by the server.
+    pub fn from_envelope(bytes: &[u8], key: &WasmClientKey) -> Result<WasmFheString, JsError> {
+        let inner = FheString::from_envelope_with_fingerprint(bytes, key.fingerprint)?;
+        Ok(WasmFheString { inner })
+    }
+}
@@ -0,0 +1,9 @@
+#!/bin/sh
+# Builds the cdylib, then builds and runs the C smoke test against it
+set -e
+
+cd "$(dirname "$0")/../.."
+
+cargo build --release --lib
+cc -Wall -Wextra -o target/release/ffi_smoke tests/ffi/smoke.c -Iinclude -Ltarget/release -lfhestring
+LD_LIBRARY_PATH=target/release DYLD_LIBRARY_PATH=target/release target/release/ffi_smoke
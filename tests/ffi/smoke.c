@@ -0,0 +1,51 @@
+/* Smoke test of the C bindings, see run.sh */
+#include <stdio.h>
+#include <string.h>
+
+#include "fhestring.h"
+
+#define CHECK(condition)                                                                       \
+    if (!(condition)) {                                                                        \
+        fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition);          \
+        return 1;                                                                              \
+    }
+
+int main(void) {
+    FfiClientKey *client_key = fhestring_client_key_new();
+    CHECK(client_key != NULL);
+    FfiServerKey *server_key = fhestring_server_key_new(client_key);
+    CHECK(server_key != NULL);
+
+    FheString *string = fhestring_encrypt(client_key, "hello test", 1);
+    FheString *pattern = fhestring_encrypt(client_key, "test", 0);
+    FheString *to = fhestring_encrypt(client_key, "zama", 0);
+    CHECK(string != NULL && pattern != NULL && to != NULL);
+
+    /* Invalid input is reported instead of aborting */
+    CHECK(fhestring_encrypt(client_key, "caf\xc3\xa9", 1) == NULL);
+    CHECK(fhestring_contains(server_key, string, NULL) == NULL);
+
+    uint8_t plain;
+    FheAsciiChar *contains = fhestring_contains(server_key, string, pattern);
+    CHECK(fhestring_decrypt_char(client_key, contains, &plain) == 0 && plain == 1);
+
+    FheAsciiChar *position = fhestring_find(server_key, string, pattern);
+    CHECK(fhestring_decrypt_char(client_key, position, &plain) == 0 && plain == 6);
+
+    FheString *replaced = fhestring_replace(server_key, string, pattern, to);
+    char *decrypted = fhestring_decrypt(client_key, replaced);
+    CHECK(decrypted != NULL && strcmp(decrypted, "hello zama") == 0);
+
+    fhestring_c_string_free(decrypted);
+    fhestring_string_free(replaced);
+    fhestring_char_free(position);
+    fhestring_char_free(contains);
+    fhestring_string_free(to);
+    fhestring_string_free(pattern);
+    fhestring_string_free(string);
+    fhestring_server_key_free(server_key);
+    fhestring_client_key_free(client_key);
+
+    printf("C smoke test passed\n");
+    return 0;
+}